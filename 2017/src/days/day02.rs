@@ -1,21 +1,24 @@
-use std::time::Instant;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use log::debug;
+pub const SOLUTION: &dyn SolveModule = &Day02;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day02;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day02 {
+    type Input = Vec<Vec<i64>>;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Vec<i64>> {
@@ -71,31 +74,19 @@ fn solve_part_2(grid: &Vec<Vec<i64>>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day02.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["5 1 9 5
-7 5 3
-2 4 6 8"];
-        let expected: [i64; 1] = [18];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["5 9 2 8
-9 4 7 3
-3 8 6 5"];
-        let expected: [i64; 1] = [9];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,25 +1,28 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
-use aoc_helpers::io::parse_int;
+use aoc_helpers::io::{ParseError, try_parse_int};
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day03;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day03;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day03 {
+    type Input = i64;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int(file_contents)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(*input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(*input).to_string()
+    }
 }
 
 fn solve_part_1(number: i64) -> i64 {
@@ -101,27 +104,19 @@ fn find_and_store_value(
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day03.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 4] = ["1", "12", "23", "1024"];
-        let expected: [i64; 4] = [0, 3, 2, 31];
-
-        for i in 0..input.len() {
-            let input = parse_int(input[i]);
-            assert_eq!(solve_part_1(input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 5] = ["1", "5", "50", "250", "500"];
-        let expected: [i64; 5] = [2, 10, 54, 304, 747];
-
-        for i in 0..input.len() {
-            let input = parse_int(input[i]);
-            assert_eq!(solve_part_2(input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
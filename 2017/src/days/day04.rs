@@ -1,22 +1,26 @@
 use std::collections::HashSet;
-use std::time::Instant;
 
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day04;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day04;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day04 {
+    type Input = Vec<Vec<String>>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Vec<String>> {
@@ -63,35 +67,19 @@ fn solve_part_2(passphrases: &Vec<Vec<String>>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day04.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 3] = [
-            ("aa bb cc dd ee", 1),
-            ("aa bb cc dd aa", 0),
-            ("aa bb cc dd aaa", 1),
-        ];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 5] = [
-            ("abcde fghij", 1),
-            ("abcde xyz ecdab", 0),
-            ("a ab abc abd abf abj", 1),
-            ("iiii oiii ooii oooi oooo", 1),
-            ("oiii ioii iioi iiio", 0),
-        ];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
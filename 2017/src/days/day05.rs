@@ -1,22 +1,24 @@
-use std::time::Instant;
+use aoc_helpers::io::{ParseError, try_parse_int_list};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use aoc_helpers::io::parse_int_list;
-use log::debug;
+pub const SOLUTION: &dyn SolveModule = &Day05;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, '\n');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day05;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day05 {
+    type Input = Vec<i64>;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, '\n')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &[i64]) -> i64 {
@@ -55,25 +57,19 @@ fn solve_part_2(input: &[i64]) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day05.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [("0\n3\n0\n1\n-3", 5)];
-
-        for (input, expected) in data {
-            let input = parse_int_list(input, '\n');
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [("0\n3\n0\n1\n-3", 10)];
-
-        for (input, expected) in data {
-            let input = parse_int_list(input, '\n');
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
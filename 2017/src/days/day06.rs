@@ -1,23 +1,27 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
-use aoc_helpers::io::parse_int_list;
-use log::{debug, trace};
+use aoc_helpers::io::{ParseError, try_parse_int_list};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, '\t');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day06;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day06;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day06 {
+    type Input = Vec<i64>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, '\t')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &[i64]) -> i64 {
@@ -97,25 +101,19 @@ fn balance_memory_banks(memory: &mut [i64], mut index: usize) {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day06.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [("2\t4\t1\t2", 5)];
-
-        for (input, expected) in data {
-            let input = parse_int_list(input, '\t');
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [("2\t4\t1\t2", 4)];
-
-        for (input, expected) in data {
-            let input = parse_int_list(input, '\t');
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
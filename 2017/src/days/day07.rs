@@ -1,22 +1,26 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day07;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day07;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, part1.to_owned());
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day07 {
+    type Input = HashMap<String, Program>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input, solve_part_1(input)).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> HashMap<String, Program> {
@@ -141,55 +145,19 @@ struct Program {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day07.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, &str); 1] = [(
-            "pbga (66)
-xhth (57)
-ebii (61)
-havc (66)
-ktlj (57)
-fwft (72) -> ktlj, cntj, xhth
-qoyq (66)
-padx (45) -> pbga, havc, qoyq
-tknk (41) -> ugml, padx, fwft
-jptl (61)
-ugml (68) -> gyxo, ebii, jptl
-gyxo (61)
-cntj (57)",
-            "tknk",
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "pbga (66)
-xhth (57)
-ebii (61)
-havc (66)
-ktlj (57)
-fwft (72) -> ktlj, cntj, xhth
-qoyq (66)
-padx (45) -> pbga, havc, qoyq
-tknk (41) -> ugml, padx, fwft
-jptl (61)
-ugml (68) -> gyxo, ebii, jptl
-gyxo (61)
-cntj (57)",
-            60,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input, "tknk".to_string()), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
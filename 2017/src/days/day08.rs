@@ -1,22 +1,27 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-use log::{debug, trace};
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day08;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day08;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Instruction> {
@@ -179,37 +184,19 @@ impl Action {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day08.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [(
-            "b inc 5 if a > 1
-a inc 1 if b < 5
-c dec -10 if a >= 1
-c inc -20 if c == 10",
-            1,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "b inc 5 if a > 1
-a inc 1 if b < 5
-c dec -10 if a >= 1
-c inc -20 if c == 10",
-            10,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
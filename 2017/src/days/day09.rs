@@ -1,22 +1,25 @@
-use std::time::Instant;
+use aoc_helpers::io::{ParseError, parse_char_list};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-use aoc_helpers::io::parse_char_list;
-use log::{debug, trace};
+pub const SOLUTION: &dyn SolveModule = &Day09;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_char_list(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day09;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day09 {
+    type Input = Vec<char>;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_char_list(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &[char]) -> i64 {
@@ -78,42 +81,19 @@ fn parse_stream(input: &[char]) -> (i64, i64) {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day09.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 8] = [
-            ("{}", 1),
-            ("{{{}}}", 6),
-            ("{{},{}}", 5),
-            ("{{{},{},{{}}}}", 16),
-            ("{<a>,<a>,<a>,<a>}", 1),
-            ("{{<ab>},{<ab>},{<ab>},{<ab>}}", 9),
-            ("{{<!!>},{<!!>},{<!!>},{<!!>}}", 9),
-            ("{{<a!>},{<a!>},{<a!>},{<ab>}}", 3),
-        ];
-
-        for (input, expected) in data {
-            let input = parse_char_list(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 7] = [
-            ("{<>}", 0),
-            ("{<random characters>}", 17),
-            ("{<<<<>}", 3),
-            ("{<{!>}>}", 2),
-            ("{<!!>}", 0),
-            ("{<!!!>>}", 0),
-            ("{<{o\"i!a,<{i<a>}", 10),
-        ];
-
-        for (input, expected) in data {
-            let input = parse_char_list(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
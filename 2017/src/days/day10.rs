@@ -1,25 +1,41 @@
 use std::collections::VecDeque;
-use std::time::Instant;
 
-use aoc_helpers::io::parse_int_list;
-use log::debug;
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::{ParseError, parse_int_list};
+use aoc_helpers::solve::{Solution, SolveModule};
 
 use crate::shared::knot_hash::{calculate_knot_hash, reverse_numbers};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, ',');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day10;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input, 256);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day10;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(file_contents);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day10 {
+    type Input = (Vec<i64>, String, i64);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((
+            parse_int_list(file_contents, ','),
+            file_contents.to_string(),
+            params.get_or("size", 256),
+        ))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, input.2).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.1).to_string()
+    }
 }
 
 fn solve_part_1(lengths: &[i64], size: i64) -> i64 {
@@ -44,29 +60,19 @@ fn solve_part_2(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day10.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64, i64); 1] = [("3,4,1,5", 5, 12)];
-
-        for (input, size, expected) in data {
-            let input = parse_int_list(input, ',');
-            assert_eq!(solve_part_1(&input, size), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, &str); 4] = [
-            ("", "a2582a3a0e66e6e86e3812dcb672a272"),
-            ("AoC 2017", "33efeb34ea91902bb2f59c9920caa6cd"),
-            ("1,2,3", "3efbe78a8d82f29979031a4aa0b16a9d"),
-            ("1,2,4", "63960835bcdc130f0b66d7ff4f6a5a8e"),
-        ];
-
-        for (input, expected) in data {
-            assert_eq!(solve_part_2(input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
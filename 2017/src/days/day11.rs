@@ -1,22 +1,25 @@
-use std::time::Instant;
-
 use aoc_helpers::hex::{HexCoordinate, HexDirection};
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+
+pub const SOLUTION: &dyn SolveModule = &Day11;
+
+struct Day11;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+impl Solution for Day11 {
+    type Input = Vec<HexDirection>;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<HexDirection> {
@@ -58,35 +61,19 @@ fn solve_part_2(directions: &[HexDirection]) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day11.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 4] = [
-            ("ne,ne,ne", 3),
-            ("ne,ne,sw,sw", 0),
-            ("ne,ne,s,s", 2),
-            ("se,sw,se,sw,sw", 3),
-        ];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 4] = [
-            ("ne,ne,ne", 3),
-            ("ne,ne,sw,sw", 2),
-            ("ne,ne,s,s", 2),
-            ("se,sw,se,sw,sw", 3),
-        ];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
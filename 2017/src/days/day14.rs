@@ -1,24 +1,29 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
 use crate::shared::knot_hash::calculate_knot_hash;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day14;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(file_contents);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day14;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(file_contents);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day14 {
+    type Input = String;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(file_contents.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &str) -> usize {
@@ -107,23 +112,19 @@ fn generate_memory(input: &str) -> HashMap<Point2d, char> {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day14.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, usize); 1] = [("flqrgnkx", 8108)];
-
-        for (input, expected) in data {
-            assert_eq!(solve_part_1(input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [("flqrgnkx", 1242)];
-
-        for (input, expected) in data {
-            assert_eq!(solve_part_2(input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
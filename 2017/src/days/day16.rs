@@ -1,22 +1,36 @@
 use std::collections::{HashMap, VecDeque};
-use std::time::Instant;
 
-use log::{debug, trace};
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day16;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input, 16);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day16;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, 16);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day16 {
+    type Input = (Vec<DanceMove>, u8);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((parse_input(file_contents), params.get_or("programs", 16)))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<DanceMove> {
@@ -158,25 +172,19 @@ struct DanceMove {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day16.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, &str); 1] = [("s1,x3/4,pe/b", "baedc")];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input, 5), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, &str); 1] = [("s1,x3/4,pe/b", "abcde")];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input, 5), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,23 +1,26 @@
 use std::collections::VecDeque;
-use std::time::Instant;
 
-use aoc_helpers::io::parse_int;
-use log::debug;
+use aoc_helpers::io::{ParseError, try_parse_int};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day17;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day17;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day17 {
+    type Input = i64;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int(file_contents)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(*input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(*input).to_string()
+    }
 }
 
 fn solve_part_1(input: i64) -> i64 {
@@ -53,25 +56,19 @@ fn solve_part_2(input: i64) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day17.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [("3", 638)];
-
-        for (input, expected) in data {
-            let input = parse_int(input);
-            assert_eq!(solve_part_1(input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [("3", 1222153)];
-
-        for (input, expected) in data {
-            let input = parse_int(input);
-            assert_eq!(solve_part_2(input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
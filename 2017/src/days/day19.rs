@@ -1,25 +1,28 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
-use aoc_helpers::io::parse_char_grid;
+use aoc_helpers::io::{ParseError, parse_char_grid};
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_char_grid(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day19;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day19;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day19 {
+    type Input = HashMap<Point2d, char>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_char_grid(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(grid: &HashMap<Point2d, char>) -> String {
@@ -110,43 +113,19 @@ fn solve_part_2(grid: &HashMap<Point2d, char>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day19.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, &str); 1] = [(
-            "     |
-     |  +--+
-     A  |  C
- F---|----E|--+
-     |  |  |  D
-     +B-+  +--+
-",
-            "ABCDEF",
-        )];
-
-        for (input, expected) in data {
-            let input = parse_char_grid(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "     |
-     |  +--+
-     A  |  C
- F---|--|-E---+
-     |  |  |  D
-     +B-+  +--+
-",
-            38,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_char_grid(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,23 +1,36 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::ParseError;
 use aoc_helpers::matrix::{flip_in_place, rotate_in_place};
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day21;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input, 5);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day21;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, 18);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day21 {
+    type Input = (HashMap<Vec<Vec<char>>, Vec<Vec<char>>>, Option<i64>);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((parse_input(file_contents), params.get("iterations")))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, input.1.unwrap_or(5)).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1.unwrap_or(18)).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> HashMap<Vec<Vec<char>>, Vec<Vec<char>>> {
@@ -168,19 +181,14 @@ fn find_enhancement<'a>(
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day21.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [(
-            "../.# => ##./#../...
-.#./..#/### => #..#/..../..../#..#",
-            12,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input, 2), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 }
@@ -1,43 +1,56 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
-use aoc_helpers::grid::get_dimensions;
-use aoc_helpers::io::parse_char_grid;
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::{ParseError, parse_char_grid};
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
+use aoc_helpers::sparse_grid::SparseGrid;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_char_grid(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day22;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day22;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, 10_000_000);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day22 {
+    type Input = (HashMap<Point2d, char>, usize);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((
+            parse_char_grid(file_contents),
+            params.get_or("bursts", 10_000_000),
+        ))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1).to_string()
+    }
 }
 
 fn solve_part_1(grid: &HashMap<Point2d, char>) -> i64 {
-    let (width, height) = get_dimensions(grid);
-
+    let mut network = get_network(grid);
     let mut infections = 0;
-    let mut network = grid.clone();
     let mut current_direction = Direction::Up;
-    let mut current_position = Point2d::new(width / 2, height / 2);
+    let mut current_position = get_centre(&network);
     for _ in 0..10_000 {
-        match network.get(&current_position) {
-            Some('.') | None => {
+        match network.get_or_default(&current_position) {
+            '.' => {
                 infections += 1;
                 network.insert(current_position, '#');
                 current_direction = current_direction.next(&Direction::Left);
             }
-            Some('#') => {
+            '#' => {
                 network.insert(current_position, '.');
                 current_direction = current_direction.next(&Direction::Right);
             }
@@ -49,27 +62,25 @@ fn solve_part_1(grid: &HashMap<Point2d, char>) -> i64 {
 }
 
 fn solve_part_2(grid: &HashMap<Point2d, char>, iterations: usize) -> i64 {
-    let (width, height) = get_dimensions(grid);
-
+    let mut network = get_network(grid);
     let mut infections = 0;
-    let mut network = grid.clone();
     let mut current_direction = Direction::Up;
-    let mut current_position = Point2d::new(width / 2, height / 2);
+    let mut current_position = get_centre(&network);
     for _ in 0..iterations {
-        match network.get(&current_position) {
-            Some('.') | None => {
+        match network.get_or_default(&current_position) {
+            '.' => {
                 network.insert(current_position, 'W');
                 current_direction = current_direction.next(&Direction::Left);
             }
-            Some('W') => {
+            'W' => {
                 infections += 1;
                 network.insert(current_position, '#');
             }
-            Some('#') => {
+            '#' => {
                 network.insert(current_position, 'F');
                 current_direction = current_direction.next(&Direction::Right);
             }
-            Some('F') => {
+            'F' => {
                 network.insert(current_position, '.');
                 current_direction = current_direction.opposite();
             }
@@ -80,37 +91,31 @@ fn solve_part_2(grid: &HashMap<Point2d, char>, iterations: usize) -> i64 {
     infections
 }
 
+fn get_network(grid: &HashMap<Point2d, char>) -> SparseGrid<char> {
+    let mut network = SparseGrid::new('.');
+    network.extend(grid.iter().map(|(point, value)| (*point, *value)));
+    network
+}
+
+fn get_centre(network: &SparseGrid<char>) -> Point2d {
+    Point2d::new(network.width() as i64 / 2, network.height() as i64 / 2)
+}
+
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day22.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [(
-            "..#
-#..
-...",
-            5587,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_char_grid(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "..#
-#..
-...",
-            26,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_char_grid(input);
-            assert_eq!(solve_part_2(&input, 100), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,88 +1,103 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::scan::{Captures, Pattern};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day25;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day25;
 
-    (part1.to_string(), "Merry Christmas!".to_string())
+impl Solution for Day25 {
+    type Input = TuringMachine;
+
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        parse_input(file_contents)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, _input: &Self::Input) -> String {
+        "Merry Christmas!".to_string()
+    }
 }
 
-fn parse_input(file_contents: &str) -> TuringMachine {
-    let mut parts = file_contents.split("\n\n");
+fn parse_input(file_contents: &str) -> Result<TuringMachine, ParseError> {
+    let file_contents = file_contents.trim_end();
+    let Some((metadata, blocks)) = file_contents.split_once("\n\n") else {
+        return Err(ParseError::at(
+            file_contents,
+            file_contents,
+            "expected states after a blank line",
+        ));
+    };
 
     // Parse metadata
-    if let Some(metadata) = parts.next()
-        && let Some((state_line, checksum_line)) = metadata.split_once('\n')
-        && let Some(state_string) = state_line.split_whitespace().last()
-        && let Some(start_state) = state_string.chars().next()
-        && let Some(checksum_string) = checksum_line.split_whitespace().nth(5)
-        && let Ok(checksum_step) = checksum_string.parse::<usize>()
-    {
-        // Parse states
-        let mut states: HashMap<char, [TuringMachineState; 2]> = HashMap::new();
-        for state in parts {
-            let mut state_parts = state.split('\n');
-            if let Some(in_state) = state_parts.next()
-                && let Some(in_state_char) = in_state.chars().nth(9)
-                // Current value 0
-                && let Some(zero_write) = state_parts.nth(1)
-                && let Some(zero_write_char) = zero_write.chars().nth(22)
-                && let Some(zero_write_value) = zero_write_char.to_digit(10)
-                && let Some(zero_slot) = state_parts.next()
-                && let Some(zero_next) = state_parts.next()
-                && let Some(zero_next_state) = zero_next.chars().nth(26)
-                // Current value 1
-                && let Some(one_write) = state_parts.nth(1)
-                && let Some(one_write_char) = one_write.chars().nth(22)
-                && let Some(one_write_value) = one_write_char.to_digit(10)
-                && let Some(one_slot) = state_parts.next()
-                && let Some(one_next) = state_parts.next()
-                && let Some(one_next_state) = one_next.chars().nth(26)
-            {
-                let zero_direction = match zero_slot.len() {
-                    32 => Direction::Left,
-                    33 => Direction::Right,
-                    _ => unreachable!(),
-                };
-                let one_direction = match one_slot.len() {
-                    32 => Direction::Left,
-                    33 => Direction::Right,
-                    _ => unreachable!(),
-                };
-
-                states.insert(
-                    in_state_char,
-                    [
-                        TuringMachineState {
-                            write_value: zero_write_value as usize,
-                            direction: zero_direction,
-                            next_state: zero_next_state,
-                        },
-                        TuringMachineState {
-                            write_value: one_write_value as usize,
-                            direction: one_direction,
-                            next_state: one_next_state,
-                        },
-                    ],
-                );
-            }
-        }
-        return TuringMachine {
-            start_state,
-            checksum_step,
-            states,
-        };
+    let (start_state, checksum_step) =
+        Pattern::new("Begin in state {}.\nPerform a diagnostic checksum after {} steps.")
+            .scan(metadata)?;
+
+    // Parse states
+    let state_pattern = Pattern::new(
+        "In state {}:
+  If the current value is 0:
+    - Write the value {}.
+    - Move one slot to the {}.
+    - Continue with state {}.
+  If the current value is 1:
+    - Write the value {}.
+    - Move one slot to the {}.
+    - Continue with state {}.",
+    );
+    let mut states: HashMap<char, [TuringMachineState; 2]> = HashMap::new();
+    for block in blocks.split("\n\n") {
+        let (state, instructions) = parse_state(&state_pattern, block)
+            .map_err(|error| error.within(file_contents, block))?;
+        states.insert(state, instructions);
     }
-    unreachable!()
+
+    Ok(TuringMachine {
+        start_state,
+        checksum_step,
+        states,
+    })
+}
+
+fn parse_state(
+    pattern: &Pattern,
+    block: &str,
+) -> Result<(char, [TuringMachineState; 2]), ParseError> {
+    let capture = pattern.captures(block)?;
+    Ok((
+        capture.get(0)?,
+        [
+            parse_instruction(block, &capture, 1)?,
+            parse_instruction(block, &capture, 4)?,
+        ],
+    ))
+}
+
+/// Parse the write value, direction and next state captured from the given index
+fn parse_instruction(
+    block: &str,
+    capture: &Captures,
+    index: usize,
+) -> Result<TuringMachineState, ParseError> {
+    let direction = match capture.text(index + 1) {
+        "left" => Direction::Left,
+        "right" => Direction::Right,
+        other => {
+            return Err(ParseError::at(block, other, "expected `left` or `right`"));
+        }
+    };
+    Ok(TuringMachineState {
+        write_value: capture.get(index)?,
+        direction,
+        next_state: capture.get(index + 2)?,
+    })
 }
 
 fn solve_part_1(input: &TuringMachine) -> usize {
@@ -122,39 +137,14 @@ struct TuringMachineState {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day25.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, usize); 1] = [(
-            "Begin in state A.
-Perform a diagnostic checksum after 6 steps.
-
-In state A:
-  If the current value is 0:
-    - Write the value 1.
-    - Move one slot to the right.
-    - Continue with state B.
-  If the current value is 1:
-    - Write the value 0.
-    - Move one slot to the left.
-    - Continue with state B.
-
-In state B:
-  If the current value is 0:
-    - Write the value 1.
-    - Move one slot to the left.
-    - Continue with state A.
-  If the current value is 1:
-    - Write the value 1.
-    - Move one slot to the right.
-    - Continue with state A.",
-            3,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 }
@@ -1,23 +1,27 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day03;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day03;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day03 {
+    type Input = HashMap<Point2d, Vec<i64>>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> HashMap<Point2d, Vec<i64>> {
@@ -90,30 +94,19 @@ fn solve_part_2(tiles: &HashMap<Point2d, Vec<i64>>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day03.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 2] = [
-            "#123 @ 3,2: 5x4",
-            "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2",
-        ];
-        let expected: [i64; 2] = [0, 4];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2"];
-        let expected: [i64; 1] = [3];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,23 +1,26 @@
 use std::collections::VecDeque;
-use std::time::Instant;
 
-use aoc_helpers::io::parse_char_list;
-use log::debug;
+use aoc_helpers::io::{ParseError, parse_char_list};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = VecDeque::from(parse_char_list(file_contents));
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day05;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day05;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day05 {
+    type Input = VecDeque<char>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(VecDeque::from(parse_char_list(file_contents)))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(units: &VecDeque<char>) -> i64 {
@@ -63,27 +66,19 @@ fn react_polymer(units: &mut VecDeque<char>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day05.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 5] = ["aA", "abBA", "abAB", "aabAAB", "dabAcCaCBAcCcaDA"];
-        let expected: [i64; 5] = [0, 0, 4, 6, 10];
-
-        for i in 0..input.len() {
-            let input = VecDeque::from(parse_char_list(input[i]));
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["dabAcCaCBAcCcaDA"];
-        let expected: [i64; 1] = [4];
-
-        for i in 0..input.len() {
-            let input = VecDeque::from(parse_char_list(input[i]));
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,23 +1,37 @@
 use std::collections::{HashSet, VecDeque};
-use std::time::Instant;
 
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (points, boundaries) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day06;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&points, &boundaries);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day06;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&points, &boundaries, 10000);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day06 {
+    type Input = (Vec<Point2d>, Boundaries, i64);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        let (points, boundaries) = parse_input(file_contents);
+        Ok((points, boundaries, params.get_or("max_distance", 10000)))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, &input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, &input.1, input.2).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (Vec<Point2d>, Boundaries) {
@@ -246,37 +260,19 @@ struct Boundaries {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day06.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["1, 1
-1, 6
-8, 3
-3, 4
-5, 5
-8, 9"];
-        let expected: [i64; 1] = [17];
-
-        for i in 0..input.len() {
-            let (points, boundaries) = parse_input(input[i]);
-            assert_eq!(solve_part_1(&points, &boundaries), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["1, 1
-1, 6
-8, 3
-3, 4
-5, 5
-8, 9"];
-        let expected: [i64; 1] = [16];
-
-        for i in 0..input.len() {
-            let (points, boundaries) = parse_input(input[i]);
-            assert_eq!(solve_part_2(&points, &boundaries, 32), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,104 +1,116 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-use log::debug;
+use aoc_helpers::examples::Params;
+use aoc_helpers::graph::{Edge, Node};
+use aoc_helpers::graph_algorithms::{TopologicalSort, find_cycle, topological_sort};
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day07;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day07;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, 5, 60);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day07 {
+    type Input = (HashMap<char, Node<char>>, usize, u8);
 
-    (part1.to_string(), part2.to_string())
-}
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
 
-fn parse_input(file_contents: &str) -> HashMap<char, Vec<char>> {
-    let mut incoming: HashMap<char, Vec<char>> = HashMap::new();
-    for line in file_contents.lines() {
-        let chars = line.chars().collect::<Vec<char>>();
-        incoming.entry(chars[5]).or_default();
-        incoming.entry(chars[36]).or_default().push(chars[5]);
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        let steps = parse_input(file_contents);
+        if let Some(cycle) = find_cycle(&steps) {
+            return Err(cycle_error(file_contents, &cycle));
+        }
+        Ok((
+            steps,
+            params.get_or("workers", 5),
+            params.get_or("step_time", 60),
+        ))
     }
-    incoming
-}
 
-fn solve_part_1(input: &HashMap<char, Vec<char>>) -> String {
-    let mut incoming: HashMap<char, Vec<char>> = input.clone();
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0).to_string()
+    }
 
-    sort_steps(&mut incoming)
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1, input.2).to_string()
+    }
 }
 
-fn solve_part_2(input: &HashMap<char, Vec<char>>, workers: usize, delay: u8) -> i64 {
-    let mut incoming: HashMap<char, Vec<char>> = input.clone();
-
-    sort_steps_multi(&mut incoming, workers, delay)
+fn parse_input(file_contents: &str) -> HashMap<char, Node<char>> {
+    let mut steps: HashMap<char, Node<char>> = HashMap::new();
+    for line in file_contents.lines() {
+        let chars = line.chars().collect::<Vec<char>>();
+        // The first step must be finished before the second step can begin
+        steps
+            .entry(chars[5])
+            .or_insert_with(|| Node::new(Vec::new()))
+            .edges
+            .push(Edge::new(chars[36], 1));
+    }
+    steps
 }
 
-fn sort_steps(incoming: &mut HashMap<char, Vec<char>>) -> String {
-    let mut result: String = String::new();
+/// Point at the instruction that closes a cycle of steps that depend on each other
+fn cycle_error(file_contents: &str, cycle: &[char]) -> ParseError {
+    let (first, last) = (cycle[0], cycle[cycle.len() - 1]);
+    let line = file_contents
+        .lines()
+        .find(|line| line.chars().nth(5) == Some(last) && line.chars().nth(36) == Some(first))
+        .unwrap_or_default();
+    let steps: Vec<String> = cycle.iter().chain([&first]).map(char::to_string).collect();
+    ParseError::at(
+        file_contents,
+        line,
+        &format!(
+            "steps depend on each other in the cycle {}",
+            steps.join(" -> ")
+        ),
+    )
+}
 
-    loop {
-        // Janky topological sort attempt
-        let next_step = get_next_step(incoming);
-        if next_step == 'x' {
-            break;
-        }
-        result.push(next_step);
-        remove_contains_node(incoming, &next_step);
+fn solve_part_1(input: &HashMap<char, Node<char>>) -> String {
+    // Default to alphabetical order if there are multiple steps available
+    match topological_sort(input, |&step| step) {
+        Some(order) => order.into_iter().collect(),
+        None => unreachable!("cycles of steps are rejected when parsing"),
     }
-
-    result
 }
 
-fn sort_steps_multi(incoming: &mut HashMap<char, Vec<char>>, workers: usize, delay: u8) -> i64 {
-    let mut result: String = String::new();
-
+fn solve_part_2(input: &HashMap<char, Node<char>>, workers: usize, delay: u8) -> i64 {
+    let mut steps = TopologicalSort::new(input, |&step| step);
     let mut pool: Vec<Process> = Vec::with_capacity(workers);
     for _ in 0..workers {
         pool.push(Process {
             ticks_remaining: 0,
-            process: 'x',
+            process: None,
         });
     }
 
     let mut tick = 0i64;
-    let mut jobs = incoming.keys().len();
-    loop {
-        if jobs == 0 {
-            break;
-        }
-
-        // Assign steps
+    while !steps.is_finished() {
+        // Assign steps to workers with no current process
         for worker in pool.iter_mut() {
-            // Attempt to assign step to worker if they have no current process
-            if worker.ticks_remaining == 0 {
-                let next_available_step = get_next_step(incoming);
-                if next_available_step != 'x' {
-                    let duration = next_available_step as u8 - b'A' + 1 + delay;
-                    worker.ticks_remaining = duration;
-                    worker.process = next_available_step;
-                }
+            if worker.process.is_none()
+                && let Some(step) = steps.pop()
+            {
+                worker.ticks_remaining = step as u8 - b'A' + 1 + delay;
+                worker.process = Some(step);
             }
         }
 
-        // Progress steps
+        // Process one tick of each step
         for worker in pool.iter_mut() {
-            // Process one tick of the step
-            if worker.process != 'x' {
+            if let Some(step) = worker.process {
                 worker.ticks_remaining -= 1;
                 if worker.ticks_remaining == 0 {
-                    // Mark node as completed
-                    remove_contains_node(incoming, &worker.process);
-                    result.push(worker.process);
-                    worker.process = 'x';
-                    jobs -= 1;
+                    steps.complete(&step);
+                    worker.process = None;
                 }
             }
         }
@@ -109,86 +121,41 @@ fn sort_steps_multi(incoming: &mut HashMap<char, Vec<char>>, workers: usize, del
     tick
 }
 
-fn get_next_step(incoming: &mut HashMap<char, Vec<char>>) -> char {
-    let incoming_clone = incoming.clone();
-    let mut empty_nodes = get_empty_nodes(&incoming_clone);
-    // Default to alphabetical order if there are multiple nodes
-    empty_nodes.sort();
-    if empty_nodes.is_empty() {
-        return 'x';
-    }
-
-    let result = empty_nodes.first();
-    if result.is_none() {
-        return 'x';
-    }
-    let char = result.unwrap();
-    remove_node(incoming, char);
-    **char
-}
-
-fn get_empty_nodes(incoming: &HashMap<char, Vec<char>>) -> Vec<&char> {
-    incoming
-        .iter()
-        .filter(|(_, v)| v.is_empty())
-        .map(|(k, _)| k)
-        .collect()
-}
-
-fn remove_node(graph: &mut HashMap<char, Vec<char>>, node: &char) {
-    graph.remove(node);
-}
-
-fn remove_contains_node(graph: &mut HashMap<char, Vec<char>>, node: &char) {
-    graph
-        .iter_mut()
-        .filter(|(_, v)| v.contains(node))
-        .for_each(|(_, v)| {
-            v.swap_remove(v.iter().position(|c| c == node).unwrap());
-        });
-}
-
 #[derive(Debug)]
 struct Process {
     ticks_remaining: u8,
-    process: char,
+    process: Option<char>,
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day07.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["Step C must be finished before step A can begin.
-Step C must be finished before step F can begin.
-Step A must be finished before step B can begin.
-Step A must be finished before step D can begin.
-Step B must be finished before step E can begin.
-Step D must be finished before step E can begin.
-Step F must be finished before step E can begin."];
-        let expected: [&str; 1] = ["CABDFE"];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["Step C must be finished before step A can begin.
-Step C must be finished before step F can begin.
-Step A must be finished before step B can begin.
-Step A must be finished before step D can begin.
-Step B must be finished before step E can begin.
-Step D must be finished before step E can begin.
-Step F must be finished before step E can begin."];
-        let expected: [i64; 1] = [15];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input, 2, 0), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let input = "Step C must be finished before step A can begin.\nStep A must be finished before step B can begin.\nStep B must be finished before step C can begin.";
+
+        // The cycle can be found from any of its steps
+        let error = SOLUTION.try_parse(input).err().unwrap();
+        assert_eq!(input.lines().nth(error.line - 1), Some(error.text.as_str()));
+        assert!(
+            error
+                .message
+                .starts_with("steps depend on each other in the cycle")
+        );
     }
 }
@@ -1,22 +1,24 @@
-use std::time::Instant;
+use aoc_helpers::io::{ParseError, try_parse_int_list};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use aoc_helpers::io::parse_int_list;
-use log::debug;
+pub const SOLUTION: &dyn SolveModule = &Day08;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, ' ');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day08;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day08 {
+    type Input = Vec<i64>;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, ' ')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(numbers: &[i64]) -> i64 {
@@ -84,27 +86,19 @@ fn calculate_root_value_rec(numbers: &[i64], start_index: usize) -> (usize, usiz
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day08.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2"];
-        let expected: [i64; 1] = [138];
-
-        for i in 0..input.len() {
-            let input = parse_int_list(input[i], ' ');
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2"];
-        let expected: [usize; 1] = [66];
-
-        for i in 0..input.len() {
-            let input = parse_int_list(input[i], ' ');
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
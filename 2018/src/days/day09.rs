@@ -1,22 +1,26 @@
 use std::collections::{HashMap, VecDeque};
-use std::time::Instant;
 
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (player_count, last_marble_value) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day09;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(player_count, last_marble_value);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day09;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(player_count, last_marble_value);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day09 {
+    type Input = (i64, i64);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input.0, input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input.0, input.1).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (i64, i64) {
@@ -72,41 +76,19 @@ fn simulate_game(player_count: i64, max_marble: i64) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day09.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 6] = [
-            "9 players; last marble is worth 25 points",
-            "10 players; last marble is worth 1618 points",
-            "13 players; last marble is worth 7999 points",
-            "17 players; last marble is worth 1104 points",
-            "21 players; last marble is worth 6111 points",
-            "30 players; last marble is worth 5807 points",
-        ];
-        let expected: [i64; 6] = [32, 8317, 146373, 2764, 54718, 37305];
-
-        for i in 0..input.len() {
-            let (player_count, last_marble_value) = parse_input(input[i]);
-            assert_eq!(solve_part_1(player_count, last_marble_value), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 6] = [
-            "9 players; last marble is worth 25 points",
-            "10 players; last marble is worth 1618 points",
-            "13 players; last marble is worth 7999 points",
-            "17 players; last marble is worth 1104 points",
-            "21 players; last marble is worth 6111 points",
-            "30 players; last marble is worth 5807 points",
-        ];
-        let expected: [i64; 6] = [22563, 74765078, 1406506154, 20548882, 507583214, 320997431];
-
-        for i in 0..input.len() {
-            let (player_count, last_marble_value) = parse_input(input[i]);
-            assert_eq!(solve_part_2(player_count, last_marble_value), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,22 +1,24 @@
-use std::time::Instant;
+use aoc_helpers::io::{ParseError, try_parse_int};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use aoc_helpers::io::parse_int;
-use log::debug;
+pub const SOLUTION: &dyn SolveModule = &Day11;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day11;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day11 {
+    type Input = i64;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int(file_contents)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(*input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(*input).to_string()
+    }
 }
 
 fn solve_part_1(serial_number: i64) -> String {
@@ -143,8 +145,12 @@ fn calculate_summed_area(
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day11.txt");
+
     #[test]
     fn test_calculate_power_level() {
         let input: [(i64, i64, i64); 4] =
@@ -235,23 +241,11 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let input: [&str; 2] = ["18", "42"];
-        let expected: [&str; 2] = ["33,45", "21,61"];
-
-        for i in 0..input.len() {
-            let input = parse_int(input[i]);
-            assert_eq!(solve_part_1(input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 2] = ["18", "42"];
-        let expected: [&str; 2] = ["90,269,16", "232,251,12"];
-
-        for i in 0..input.len() {
-            let input = parse_int(input[i]);
-            assert_eq!(solve_part_2(input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,22 +1,26 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (state, mutations) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day12;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&state, &mutations);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day12;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&state, &mutations);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day12 {
+    type Input = (Vec<char>, HashMap<String, char>);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, &input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, &input.1).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (Vec<char>, HashMap<String, char>) {
@@ -130,57 +134,19 @@ fn calculate_score(state: &[char], offset: i64) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day12.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["initial state: #..#.#..##......###...###
-
-...## => #
-..#.. => #
-.#... => #
-.#.#. => #
-.#.## => #
-.##.. => #
-.#### => #
-#.#.# => #
-#.### => #
-##.#. => #
-##.## => #
-###.. => #
-###.# => #
-####. => #"];
-        let expected: [i64; 1] = [325];
-
-        for i in 0..input.len() {
-            let (state, mutations) = parse_input(input[i]);
-            assert_eq!(solve_part_1(&state, &mutations), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["initial state: #..#.#..##......###...###
-
-...## => #
-..#.. => #
-.#... => #
-.#.#. => #
-.#.## => #
-.##.. => #
-.#### => #
-#.#.# => #
-#.### => #
-##.#. => #
-##.## => #
-###.. => #
-###.# => #
-####. => #"];
-        let expected: [i64; 1] = [999999999374];
-
-        for i in 0..input.len() {
-            let (state, mutations) = parse_input(input[i]);
-            assert_eq!(solve_part_2(&state, &mutations), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
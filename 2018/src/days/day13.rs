@@ -1,24 +1,28 @@
 use std::collections::{HashMap, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (tracks, carts) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day13;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&tracks, &carts);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day13;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&tracks, &carts);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day13 {
+    type Input = (HashMap<Point2d, char>, Vec<Cart>);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, &input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, &input.1).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (HashMap<Point2d, char>, Vec<Cart>) {
@@ -237,38 +241,19 @@ enum Turn {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day13.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["/->-\\
-|   |  /----\\
-| /-+--+-\\  |
-| | |  | v  |
-\\-+-/  \\-+--/
-  \\------/"];
-        let expected: [Point2d; 1] = [Point2d::new(7, 3)];
-
-        for i in 0..input.len() {
-            let (tracks, carts) = parse_input(input[i]);
-            assert_eq!(solve_part_1(&tracks, &carts), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["/>-<\\
-|   |
-| /<+-\\
-| | | v
-\\>+</ |
-  |   ^
-  \\<->/"];
-        let expected: [Point2d; 1] = [Point2d::new(6, 4)];
-
-        for i in 0..input.len() {
-            let (tracks, carts) = parse_input(input[i]);
-            assert_eq!(solve_part_2(&tracks, &carts), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
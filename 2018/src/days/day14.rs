@@ -1,23 +1,26 @@
 use std::collections::VecDeque;
-use std::time::Instant;
 
-use aoc_helpers::io::parse_int;
-use log::debug;
+use aoc_helpers::io::{ParseError, try_parse_int};
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day14;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day14;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(file_contents);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day14 {
+    type Input = (i64, String);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok((try_parse_int(file_contents)?, file_contents.to_string()))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input.0).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.1).to_string()
+    }
 }
 
 fn solve_part_1(input: i64) -> String {
@@ -111,26 +114,19 @@ fn solve_part_2(input: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day14.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 4] = ["9", "5", "18", "2018"];
-        let expected: [&str; 4] = ["5158916779", "0124515891", "9251071085", "5941429882"];
-
-        for i in 0..input.len() {
-            let input = parse_int(input[i]);
-            assert_eq!(solve_part_1(input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 4] = ["51589", "01245", "92510", "59414"];
-        let expected: [usize; 4] = [9, 5, 18, 2018];
-
-        for i in 0..input.len() {
-            assert_eq!(solve_part_2(input[i]), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
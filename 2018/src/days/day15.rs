@@ -1,24 +1,28 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (battlefield, units) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day15;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&battlefield, &units);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day15;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&battlefield, &units);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day15 {
+    type Input = (HashMap<Point2d, char>, HashMap<Point2d, Unit>);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, &input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, &input.1).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (HashMap<Point2d, char>, HashMap<Point2d, Unit>) {
@@ -288,117 +292,19 @@ struct Unit {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day15.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 6] = [
-            "#######
-#.G...#
-#...EG#
-#.#.#G#
-#..G#E#
-#.....#
-#######",
-            "#######
-#G..#E#
-#E#E.E#
-#G.##.#
-#...#E#
-#...E.#
-#######",
-            "#######
-#E..EG#
-#.#G.E#
-#E.##E#
-#G..#.#
-#..E#.#
-#######",
-            "#######
-#E.G#.#
-#.#G..#
-#G.#.G#
-#G..#.#
-#...E.#
-#######",
-            "#######
-#.E...#
-#.#..G#
-#.###.#
-#E#G#G#
-#...#G#
-#######",
-            "#########
-#G......#
-#.E.#...#
-#..##..G#
-#...##..#
-#...#...#
-#.G...G.#
-#.....G.#
-#########",
-        ];
-        let expected: [i64; 6] = [27730, 36334, 39514, 27755, 28944, 18740];
-
-        for i in 0..input.len() {
-            let (battlefield, units) = parse_input(input[i]);
-            assert_eq!(solve_part_1(&battlefield, &units), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 6] = [
-            "#######
-#.G...#
-#...EG#
-#.#.#G#
-#..G#E#
-#.....#
-#######",
-            "#######
-#G..#E#
-#E#E.E#
-#G.##.#
-#...#E#
-#...E.#
-#######",
-            "#######
-#E..EG#
-#.#G.E#
-#E.##E#
-#G..#.#
-#..E#.#
-#######",
-            "#######
-#E.G#.#
-#.#G..#
-#G.#.G#
-#G..#.#
-#...E.#
-#######",
-            "#######
-#.E...#
-#.#..G#
-#.###.#
-#E#G#G#
-#...#G#
-#######",
-            "#########
-#G......#
-#.E.#...#
-#..##..G#
-#...##..#
-#...#...#
-#.G...G.#
-#.....G.#
-#########",
-        ];
-        let expected: [i64; 6] = [4988, 29064, 31284, 3478, 6474, 1140];
-
-        for i in 0..input.len() {
-            let (battlefield, units) = parse_input(input[i]);
-            assert_eq!(solve_part_2(&battlefield, &units), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,22 +1,26 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (samples, instructions) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day16;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&samples);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day16;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&samples, &instructions);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day16 {
+    type Input = (Vec<Sample>, Vec<Instruction>);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, &input.1).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (Vec<Sample>, Vec<Instruction>) {
@@ -1,24 +1,28 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let (map, min_y, max_y) = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day17;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&map, min_y, max_y);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day17;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&map, min_y, max_y);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day17 {
+    type Input = (HashMap<Point2d, char>, i64, i64);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, input.1, input.2).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1, input.2).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> (HashMap<Point2d, char>, i64, i64) {
@@ -300,41 +304,19 @@ fn flood_horizontal(
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day17.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["x=495, y=2..7
-y=7, x=495..501
-x=501, y=3..7
-x=498, y=2..4
-x=506, y=1..2
-x=498, y=10..13
-x=504, y=10..13
-y=13, x=498..504"];
-        let expected: [usize; 1] = [57];
-
-        for i in 0..input.len() {
-            let (map, min_y, max_y) = parse_input(input[i]);
-            assert_eq!(solve_part_1(&map, min_y, max_y), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["x=495, y=2..7
-y=7, x=495..501
-x=501, y=3..7
-x=498, y=2..4
-x=506, y=1..2
-x=498, y=10..13
-x=504, y=10..13
-y=13, x=498..504"];
-        let expected: [usize; 1] = [29];
-
-        for i in 0..input.len() {
-            let (map, min_y, max_y) = parse_input(input[i]);
-            assert_eq!(solve_part_2(&map, min_y, max_y), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
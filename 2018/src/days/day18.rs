@@ -1,89 +1,57 @@
 use std::collections::HashMap;
 use std::mem::swap;
-use std::time::Instant;
 
-use aoc_helpers::io::parse_char_vec;
-use log::{debug, trace};
+use aoc_helpers::grid::Grid;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_char_vec(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day18;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day18;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day18 {
+    type Input = Grid<char>;
 
-    (part1.to_string(), part2.to_string())
-}
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Grid::try_parse_with(file_contents, |c| matches!(c, '.' | '|' | '#').then_some(c))
+    }
 
-fn solve_part_1(input: &[Vec<char>]) -> i64 {
-    let size = input.len();
-    let mut current: Vec<Vec<char>> = vec![vec!['.'; size]; size];
-    for y in 0..size {
-        for x in 0..size {
-            current[y][x] = input[y][x];
-        }
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
     }
-    let mut future: Vec<Vec<char>> = vec![vec!['.'; size]; size];
 
-    for _ in 0..10 {
-        simulate_landscape(&current, &mut future, size);
-        swap(&mut current, &mut future);
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
     }
+}
 
-    let mut trees = 0;
-    let mut lumberyards = 0;
-    for row in current {
-        for value in row {
-            match value {
-                '|' => trees += 1,
-                '#' => lumberyards += 1,
-                _ => continue,
-            }
-        }
+fn solve_part_1(input: &Grid<char>) -> usize {
+    let mut current = input.clone();
+    let mut future = input.clone();
+
+    for _ in 0..10 {
+        simulate_landscape(&current, &mut future);
+        swap(&mut current, &mut future);
     }
 
+    let (_, trees, lumberyards) = count_acres(&current);
     trees * lumberyards
 }
 
-fn solve_part_2(input: &[Vec<char>]) -> i64 {
-    let size = input.len();
-    let mut current: Vec<Vec<char>> = vec![vec!['.'; size]; size];
-    for y in 0..size {
-        for x in 0..size {
-            current[y][x] = input[y][x];
-        }
-    }
-    let mut future: Vec<Vec<char>> = vec![vec!['.'; size]; size];
-    let mut states: HashMap<(i64, i64, i64), (usize, usize)> = HashMap::new();
+fn solve_part_2(input: &Grid<char>) -> usize {
+    let mut current = input.clone();
+    let mut future = input.clone();
+    let mut states: HashMap<(usize, usize, usize), (usize, usize)> = HashMap::new();
 
     const MAX: usize = 1_000_000_000;
     let mut minute = 0;
     loop {
-        simulate_landscape(&current, &mut future, size);
+        simulate_landscape(&current, &mut future);
         swap(&mut current, &mut future);
         minute += 1;
-        let mut open = 0;
-        let mut trees = 0;
-        let mut lumberyards = 0;
-        for row in &current {
-            for &value in row {
-                match value {
-                    '.' => open += 1,
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => continue,
-                }
-            }
-        }
-
         // Store the last time this state was seen and the delta between when it was last seen
-        let key = (open, trees, lumberyards);
+        let key = count_acres(&current);
         if let Some((last_seen, delta)) = states.get(&key) {
             // We have seen the same state twice after the same number of states
             if minute - last_seen == *delta {
@@ -97,21 +65,11 @@ fn solve_part_2(input: &[Vec<char>]) -> i64 {
 
                 // Process the remaining minutes needed to reach 1,000,000,000
                 for _ in 0..offset {
-                    simulate_landscape(&current, &mut future, size);
+                    simulate_landscape(&current, &mut future);
                     swap(&mut current, &mut future);
                 }
 
-                trees = 0;
-                lumberyards = 0;
-                for row in &current {
-                    for &value in row {
-                        match value {
-                            '|' => trees += 1,
-                            '#' => lumberyards += 1,
-                            _ => continue,
-                        }
-                    }
-                }
+                let (_, trees, lumberyards) = count_acres(&current);
                 return trees * lumberyards;
             }
             states.insert(key, (minute, minute - last_seen));
@@ -121,126 +79,54 @@ fn solve_part_2(input: &[Vec<char>]) -> i64 {
     }
 }
 
-fn simulate_landscape(current: &[Vec<char>], future: &mut [Vec<char>], size: usize) {
-    for y in 0..size {
-        for x in 0..size {
-            let mut trees = 0;
-            let mut lumberyards = 0;
-
-            // Up left
-            if x > 0 && y > 0 {
-                match current[y - 1][x - 1] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Up
-            if y > 0 {
-                match current[y - 1][x] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Up right
-            if y > 0 && x < size - 1 {
-                match current[y - 1][x + 1] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Left
-            if x > 0 {
-                match current[y][x - 1] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Right
-            if x < size - 1 {
-                match current[y][x + 1] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Down left
-            if y < size - 1 && x > 0 {
-                match current[y + 1][x - 1] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Down
-            if y < size - 1 {
-                match current[y + 1][x] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
-            }
-            // Down right
-            if y < size - 1 && x < size - 1 {
-                match current[y + 1][x + 1] {
-                    '|' => trees += 1,
-                    '#' => lumberyards += 1,
-                    _ => (),
-                }
+/// Count the open, tree and lumberyard acres in a single pass
+fn count_acres(landscape: &Grid<char>) -> (usize, usize, usize) {
+    let mut counts = (0, 0, 0);
+    for row in landscape.rows() {
+        for &value in row {
+            match value {
+                '.' => counts.0 += 1,
+                '|' => counts.1 += 1,
+                '#' => counts.2 += 1,
+                _ => (),
             }
+        }
+    }
+    counts
+}
 
-            match current[y][x] {
-                '.' => {
-                    if trees >= 3 {
-                        future[y][x] = '|';
-                    } else {
-                        future[y][x] = '.';
-                    }
-                }
-                '|' => {
-                    if lumberyards >= 3 {
-                        future[y][x] = '#';
-                    } else {
-                        future[y][x] = '|';
-                    }
-                }
-                '#' => {
-                    if lumberyards >= 1 && trees >= 1 {
-                        future[y][x] = '#';
-                    } else {
-                        future[y][x] = '.';
-                    }
-                }
-                _ => unreachable!("invalid character found"),
+fn simulate_landscape(current: &Grid<char>, future: &mut Grid<char>) {
+    for (point, &value) in current.iter() {
+        let mut trees = 0;
+        let mut lumberyards = 0;
+        for (_, neighbour) in current.neighbours8(&point) {
+            match neighbour {
+                '|' => trees += 1,
+                '#' => lumberyards += 1,
+                _ => (),
             }
         }
+
+        future[point] = match value {
+            '.' if trees >= 3 => '|',
+            '|' if lumberyards >= 3 => '#',
+            '#' if lumberyards == 0 || trees == 0 => '.',
+            '.' | '|' | '#' => value,
+            _ => unreachable!("invalid character found"),
+        };
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day18.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = [".#.#...|#.
-.....#|##|
-.|..|...#.
-..|#.....#
-#.#|||#|#|
-...#.||...
-.|....|...
-||...#|.#|
-|.||||..|.
-...#.|..|."];
-        let expected: [i64; 1] = [1147];
-
-        for i in 0..input.len() {
-            let input = parse_char_vec(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 }
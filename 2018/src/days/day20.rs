@@ -1,24 +1,29 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::direction::{Direction, get_directions};
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day20;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day20;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day20 {
+    type Input = HashMap<Point2d, char>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> HashMap<Point2d, char> {
@@ -184,22 +189,14 @@ fn solve_part_2(maze: &HashMap<Point2d, char>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day20.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 5] = [
-            "^WNE$",
-            "^ENWWW(NEEE|SSE(EE|N))$",
-            "^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$",
-            "^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$",
-            "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$",
-        ];
-        let expected: [i64; 5] = [3, 10, 18, 23, 31];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 }
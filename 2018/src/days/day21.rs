@@ -1,24 +1,29 @@
 use std::collections::HashSet;
-use std::time::Instant;
 
-use log::{debug, trace};
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
 use crate::shared::elfcode::{Device, Instruction, parse_device_instructions};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_device_instructions(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day21;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day21;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2();
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day21 {
+    type Input = Vec<Instruction>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_device_instructions(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, _input: &Self::Input) -> String {
+        solve_part_2().to_string()
+    }
 }
 
 fn solve_part_1(instructions: &[Instruction]) -> usize {
@@ -1,24 +1,29 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point2d::Point2d;
-use aoc_helpers::priority_queue::{PriorityQueue, PriorityQueueItem};
-use log::{debug, trace};
+use aoc_helpers::search::astar;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day22;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day22;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day22 {
+    type Input = ScanInfo;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> ScanInfo {
@@ -87,82 +92,71 @@ fn solve_part_2(input: &ScanInfo) -> i64 {
     shared_items_map.insert((Region::Narrow, Region::Rocky), vec![Equipment::Torch]);
     shared_items_map.insert((Region::Narrow, Region::Wet), vec![Equipment::Neither]);
 
-    let mut queue: PriorityQueue<PriorityQueueItem<(Point2d, Equipment)>> = PriorityQueue::new();
-    let mut visited: HashMap<(Point2d, Equipment), i64> = HashMap::new();
-
-    queue.push(PriorityQueueItem::new(
-        0,
+    let result = astar(
         (Point2d::new(0, 0), Equipment::Torch),
-    ));
-
-    while let Some(PriorityQueueItem {
-        weight: minutes,
-        data: (coordinate, equipment),
-    }) = queue.pop()
-    {
-        trace!(
-            "checking coordinate {} with {:?} equipped {} minutes elapsed",
-            coordinate, equipment, minutes
-        );
-
-        if coordinate == input.target && equipment == Equipment::Torch {
+        |&(coordinate, equipment)| coordinate == input.target && equipment == Equipment::Torch,
+        |&(coordinate, equipment)| {
             trace!(
-                "found target position with {:?} equipped after {} minutes",
-                equipment, minutes
+                "checking coordinate {} with {:?} equipped",
+                coordinate, equipment
             );
-            return minutes;
-        }
 
-        let key = (coordinate, equipment);
-        if let Some(&distance) = visited.get(&key)
-            && distance <= minutes
-        {
-            trace!(
-                "we have seen this exact state {:?} so it can be skipped",
-                coordinate
-            );
-            continue;
-        }
-        visited.insert(key, minutes);
-
-        let source_region =
-            calculate_region_type(&coordinate, &input.target, input.depth, &mut cache);
-        for neighbour in coordinate.neighbours() {
-            trace!("checking neighbour {} with {:?}", neighbour, equipment);
-            // Limit the search space to target x/y + 100
-            // This allows the shortest path to be found with detours but prevents runaway paths
-            if neighbour.x < 0
-                || neighbour.y < 0
-                || neighbour.x > input.target.x + 100
-                || neighbour.y > input.target.y + 100
-            {
-                trace!("neighbour {} is out of bounds.", neighbour);
-                continue;
-            }
+            let mut next: Vec<((Point2d, Equipment), i64)> = Vec::new();
+            let source_region =
+                calculate_region_type(&coordinate, &input.target, input.depth, &mut cache);
+            for neighbour in coordinate.neighbours() {
+                trace!("checking neighbour {} with {:?}", neighbour, equipment);
+                // Limit the search space to target x/y + 100
+                // This allows the shortest path to be found with detours but prevents runaway paths
+                if neighbour.x < 0
+                    || neighbour.y < 0
+                    || neighbour.x > input.target.x + 100
+                    || neighbour.y > input.target.y + 100
+                {
+                    trace!("neighbour {} is out of bounds.", neighbour);
+                    continue;
+                }
 
-            let neighbour_region =
-                calculate_region_type(&neighbour, &input.target, input.depth, &mut cache);
-            if let Some(shared_items) = shared_items_map.get(&(source_region, neighbour_region)) {
-                trace!("shared items: {:?}", shared_items);
-                for &item in shared_items {
-                    if item == equipment {
-                        trace!(
-                            "moving to {} without switching items and keeping {:?}",
-                            neighbour, equipment
-                        );
-                        queue.push(PriorityQueueItem::new(minutes + 1, (neighbour, equipment)));
-                    } else {
-                        trace!(
-                            "moving to {} after switching from {:?} to {:?}",
-                            neighbour, equipment, item
-                        );
-                        queue.push(PriorityQueueItem::new(minutes + 8, (neighbour, item)));
+                let neighbour_region =
+                    calculate_region_type(&neighbour, &input.target, input.depth, &mut cache);
+                if let Some(shared_items) = shared_items_map.get(&(source_region, neighbour_region))
+                {
+                    trace!("shared items: {:?}", shared_items);
+                    for &item in shared_items {
+                        if item == equipment {
+                            trace!(
+                                "moving to {} without switching items and keeping {:?}",
+                                neighbour, equipment
+                            );
+                            next.push(((neighbour, equipment), 1));
+                        } else {
+                            trace!(
+                                "moving to {} after switching from {:?} to {:?}",
+                                neighbour, equipment, item
+                            );
+                            next.push(((neighbour, item), 8));
+                        }
                     }
                 }
             }
+            next
+        },
+        // The torch must be equipped at the target, which takes 7 minutes if it is not already
+        |&(coordinate, equipment)| {
+            let switch = if equipment == Equipment::Torch { 0 } else { 7 };
+            coordinate.manhattan(&input.target) + switch
+        },
+    );
+    match result {
+        Some(result) => {
+            trace!(
+                "found target position with torch equipped after {} minutes",
+                result.distance
+            );
+            result.distance
         }
+        None => unreachable!(),
     }
-    unreachable!();
 }
 
 fn calculate_geologic_index(
@@ -279,8 +273,12 @@ impl Cache {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day22.txt");
+
     #[test]
     fn test_calculate_region_type() {
         let input: [Point2d; 5] = [
@@ -313,25 +311,11 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["depth: 510
-target: 10,10"];
-        let expected: [i64; 1] = [114];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["depth: 510
-target: 10,10"];
-        let expected: [i64; 1] = [45];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
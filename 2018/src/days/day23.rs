@@ -1,45 +1,42 @@
 use std::cmp::max;
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point3d::Point3d;
 use aoc_helpers::priority_queue::{PriorityQueue, PriorityQueueItem};
-use log::{debug, trace};
+use aoc_helpers::scan::Pattern;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day23;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day23;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day23 {
+    type Input = Vec<NanoBot>;
 
-    (part1.to_string(), part2.to_string())
-}
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        parse_input(file_contents)
+    }
 
-fn parse_input(file_contents: &str) -> Vec<NanoBot> {
-    let mut bots: Vec<NanoBot> = Vec::new();
-    for line in file_contents.lines() {
-        let mut numbers: Vec<i64> = Vec::with_capacity(4);
-        for item in line.split(',') {
-            let cleaned = item
-                .chars()
-                .filter(|&c| c == '-' || c.is_ascii_digit())
-                .collect::<String>();
-            if let Ok(number) = cleaned.parse::<i64>() {
-                numbers.push(number);
-            }
-        }
-        bots.push(NanoBot {
-            position: Point3d::new(numbers[0], numbers[1], numbers[2]),
-            radius: numbers[3],
-        });
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
     }
-    bots
+}
+
+fn parse_input(file_contents: &str) -> Result<Vec<NanoBot>, ParseError> {
+    let bots = Pattern::new("pos=<{},{},{}>, r={}")
+        .scan_lines(file_contents)?
+        .into_iter()
+        .map(|(x, y, z, radius)| NanoBot {
+            position: Point3d::new(x, y, z),
+            radius,
+        })
+        .collect();
+    Ok(bots)
 }
 
 fn solve_part_1(input: &[NanoBot]) -> i64 {
@@ -117,40 +114,19 @@ struct NanoBot {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day23.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["pos=<0,0,0>, r=4
-pos=<1,0,0>, r=1
-pos=<4,0,0>, r=3
-pos=<0,2,0>, r=1
-pos=<0,5,0>, r=3
-pos=<0,0,3>, r=1
-pos=<1,1,1>, r=1
-pos=<1,1,2>, r=1
-pos=<1,3,1>, r=1"];
-        let expected: [i64; 1] = [7];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["pos=<10,12,12>, r=2
-pos=<12,14,12>, r=2
-pos=<16,12,12>, r=4
-pos=<14,14,14>, r=6
-pos=<50,50,50>, r=200
-pos=<10,10,10>, r=5"];
-        let expected: [i64; 1] = [36];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
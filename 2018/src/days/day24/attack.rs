@@ -36,9 +36,3 @@ impl fmt::Display for Attack {
         }
     }
 }
-
-impl Attack {
-    pub fn new(attack_type: &str) -> Result<Self, ()> {
-        Attack::from_str(attack_type)
-    }
-}
@@ -4,91 +4,97 @@ mod group;
 
 use std::cmp::{Ordering, min};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
+use aoc_helpers::scan::Pattern;
+use aoc_helpers::solve::{Solution, SolveModule};
 use log::{debug, trace};
-use regex::Regex;
 
-use attack::Attack;
 use faction::Faction;
 use group::Group;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day24;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day24;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day24 {
+    type Input = Vec<Group>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        parse_input(file_contents)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
-fn parse_input(file_contents: &str) -> Vec<Group> {
+fn parse_input(file_contents: &str) -> Result<Vec<Group>, ParseError> {
+    let Some((immune_system, infection)) = file_contents.split_once("\n\n") else {
+        return Err(ParseError::at(
+            file_contents,
+            file_contents,
+            "expected two armies separated by a blank line",
+        ));
+    };
+
+    let group_pattern = Pattern::new(
+        "{} units each with {} hit points {?({}) }with an attack that does {} {} damage at initiative {}",
+    );
+    let modifier_pattern = Pattern::new("{} to {}{*, {}}");
     let mut groups: Vec<Group> = Vec::new();
-    if let Ok(matcher) = Regex::new(
-        r"(?<units>\d+) units each with (?<hp>\d+) hit points (\((?<modifiers>.*)\)\s)?with an attack that does (?<damage>\d+) (?<type>\w+) damage at initiative (?<initiative>\d+)",
-    ) && let Some((immune_system, infection)) = file_contents.split_once("\n\n")
-    {
-        for (i, line) in immune_system.lines().skip(1).enumerate() {
-            groups.push(parse_group(&matcher, line, Faction::ImmuneSystem, i + 1));
-        }
-
-        for (i, line) in infection.lines().skip(1).enumerate() {
-            groups.push(parse_group(&matcher, line, Faction::Infection, i + 1));
+    for (faction, army) in [
+        (Faction::ImmuneSystem, immune_system),
+        (Faction::Infection, infection),
+    ] {
+        for (i, line) in army.lines().skip(1).enumerate() {
+            let group = parse_group(&group_pattern, &modifier_pattern, line, faction, i + 1)
+                .map_err(|error| error.within(file_contents, line))?;
+            groups.push(group);
         }
     }
-    groups
+    Ok(groups)
 }
 
-fn parse_group(matcher: &Regex, line: &str, faction: Faction, id: usize) -> Group {
-    if let Some(capture) = matcher.captures(line)
-        && let Some(u) = capture.name("units")
-        && let Ok(units) = u.as_str().parse::<i64>()
-        && let Some(hp) = capture.name("hp")
-        && let Ok(hit_points) = hp.as_str().parse::<i64>()
-        && let Some(ad) = capture.name("damage")
-        && let Ok(attack_damage) = ad.as_str().parse::<i64>()
-        && let Some(at) = capture.name("type")
-        && let Ok(attack_type) = Attack::new(at.as_str())
-        && let Some(i) = capture.name("initiative")
-        && let Ok(initiative) = i.as_str().parse::<i64>()
-    {
-        let mut group = Group::new(
-            faction,
-            id,
-            units,
-            hit_points,
-            attack_damage,
-            attack_type,
-            initiative,
-        );
-
-        if let Some(m) = capture.name("modifiers") {
-            for modifier in m.as_str().split("; ") {
-                if let Some((t, ats)) = modifier.split_once(" to ") {
-                    let to_add = match t {
-                        "weak" => &mut group.weaknesses,
-                        "immune" => &mut group.immunities,
-                        _ => unreachable!(),
-                    };
-                    for t in ats.split(", ") {
-                        if let Ok(converted) = Attack::new(t) {
-                            to_add.push(converted);
-                        }
-                    }
-                }
+fn parse_group(
+    group_pattern: &Pattern,
+    modifier_pattern: &Pattern,
+    line: &str,
+    faction: Faction,
+    id: usize,
+) -> Result<Group, ParseError> {
+    let capture = group_pattern.captures(line)?;
+    let mut group = Group::new(
+        faction,
+        id,
+        capture.get(0)?,
+        capture.get(1)?,
+        capture.get(3)?,
+        capture.get(4)?,
+        capture.get(5)?,
+    );
+
+    if let Some(modifiers) = capture.optional(2) {
+        for modifier in modifiers.text(0).split("; ") {
+            let m = modifier_pattern
+                .captures(modifier)
+                .map_err(|error| error.within(line, modifier))?;
+            let to_add = match m.text(0) {
+                "weak" => &mut group.weaknesses,
+                "immune" => &mut group.immunities,
+                other => return Err(ParseError::at(line, other, "expected `weak` or `immune`")),
+            };
+            to_add.push(m.get(1).map_err(|error| error.within(line, modifier))?);
+            for t in m.repeated(2) {
+                to_add.push(t.get(0).map_err(|error| error.within(line, modifier))?);
             }
         }
-
-        return group;
     }
-    panic!("unable to parse group");
+    Ok(group)
 }
 
 fn solve_part_1(input: &[Group]) -> i64 {
@@ -266,43 +272,19 @@ fn attack_targets(groups: &mut Vec<Group>, targets: &mut Vec<(Group, Group)>) {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../../examples/day24.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = [
-            "Immune System:
-17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
-989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
-
-Infection:
-801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
-4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4",
-        ];
-        let expected: [i64; 1] = [5216];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = [
-            "Immune System:
-17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
-989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
-
-Infection:
-801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
-4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4",
-        ];
-        let expected: [i64; 1] = [51];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
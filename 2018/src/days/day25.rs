@@ -1,20 +1,27 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
-
-use aoc_helpers::graph::{Edge, Node};
+use aoc_helpers::io::ParseError;
 use aoc_helpers::point4d::Point4d;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use aoc_helpers::union_find::KeyedDisjointSet;
+use log::trace;
+
+pub const SOLUTION: &dyn SolveModule = &Day25;
+
+struct Day25;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+impl Solution for Day25 {
+    type Input = Vec<Point4d>;
+
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), "Merry Christmas!".to_string())
+    fn part2(&self, _input: &Self::Input) -> String {
+        "Merry Christmas!".to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Point4d> {
@@ -32,15 +39,11 @@ fn parse_input(file_contents: &str) -> Vec<Point4d> {
 }
 
 fn solve_part_1(input: &[Point4d]) -> i64 {
-    let mut graph: HashMap<Point4d, Node<Point4d>> = HashMap::new();
-    for coordinate in input {
-        let mut edges: Vec<Edge<Point4d>> = Vec::new();
+    let mut constellations: KeyedDisjointSet<Point4d> = KeyedDisjointSet::new();
+    for (i, coordinate) in input.iter().enumerate() {
         trace!("Checking coordinate: {}", coordinate);
-        for other in input {
-            if coordinate == other {
-                continue;
-            }
-
+        constellations.insert(*coordinate);
+        for other in &input[i + 1..] {
             let manhattan_distance = coordinate.manhattan(other);
             trace!(
                 "Checking distance from {} to: {} {}",
@@ -48,94 +51,24 @@ fn solve_part_1(input: &[Point4d]) -> i64 {
             );
             if manhattan_distance <= 3 {
                 trace!("{} is connected to {}", coordinate, other);
-                edges.push(Edge::new(*other, manhattan_distance));
+                constellations.union(*coordinate, *other);
             }
         }
-        graph.insert(*coordinate, Node::new(edges));
     }
 
-    let mut total = 0;
-    let mut visited: HashSet<Point4d> = HashSet::new();
-    for (origin, node) in &graph {
-        trace!("Origin: {} Edges: {:?}", origin, node.edges);
-        if visited.contains(origin) {
-            continue;
-        }
-        visited.insert(*origin);
-        total += 1;
-
-        let mut queue: VecDeque<Point4d> = VecDeque::new();
-        queue.push_back(*origin);
-
-        while let Some(current) = queue.pop_front() {
-            trace!("Checking {}", current);
-
-            if let Some(node) = graph.get(&current) {
-                for edge in &node.edges {
-                    trace!("Found connected node {}", edge.value);
-                    if visited.contains(&edge.value) {
-                        continue;
-                    }
-                    visited.insert(edge.value);
-                    queue.push_back(edge.value);
-                }
-            }
-        }
-    }
-    total
+    constellations.component_count() as i64
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day25.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 4] = [
-            "0,0,0,0
-3,0,0,0
-0,3,0,0
-0,0,3,0
-0,0,0,3
-0,0,0,6
-9,0,0,0
-12,0,0,0",
-            "-1,2,2,0
-0,0,2,-2
-0,0,0,-2
--1,2,0,0
--2,-2,-2,2
-3,0,2,-1
--1,3,2,2
--1,0,-1,0
-0,2,1,-2
-3,0,0,0",
-            "1,-1,0,1
-2,0,-1,0
-3,2,-1,0
-0,0,3,1
-0,0,-1,-1
-2,3,-2,0
--2,2,0,0
-2,-2,0,-1
-1,-1,0,-1
-3,2,0,2",
-            "1,-1,-1,-2
--2,-2,0,1
-0,2,1,3
--2,3,-2,1
-0,2,3,-2
--1,-1,1,-2
-0,-2,-1,0
--2,2,3,-1
-1,2,2,0
--1,-2,0,-2",
-        ];
-        let expected: [i64; 4] = [2, 4, 3, 8];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 }
@@ -1,24 +1,27 @@
-use std::time::Instant;
-
-use aoc_helpers::io::parse_int_list;
-use log::{debug, trace};
+use aoc_helpers::io::{ParseError, try_parse_int_list};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
 use crate::shared::intcode::IntCodeComputer;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, ',');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day02;
+
+struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<i64>;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, ',')
+    }
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &[i64]) -> i64 {
@@ -1,24 +1,28 @@
 use std::cmp::{max, min};
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
 use aoc_helpers::line::LineSegment2d;
 use aoc_helpers::point2d::Point2d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day03;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day03;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day03 {
+    type Input = (Vec<LineData>, Vec<LineData>);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 pub fn parse_input(file_contents: &str) -> (Vec<LineData>, Vec<LineData>) {
@@ -157,41 +161,19 @@ pub struct LineData {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day03.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 3] = [
-            "R8,U5,L5,D3
-U7,R6,D4,L4",
-            "R75,D30,R83,U83,L12,D49,R71,U7,L72
-U62,R66,U55,R34,D71,R55,D58,R83",
-            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
-U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
-        ];
-        let expected: [i64; 3] = [6, 159, 135];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_1(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 3] = [
-            "R8,U5,L5,D3
-U7,R6,D4,L4",
-            "R75,D30,R83,U83,L12,D49,R71,U7,L72
-U62,R66,U55,R34,D71,R55,D58,R83",
-            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
-U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
-        ];
-        let expected: [i64; 3] = [30, 610, 410];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_2(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
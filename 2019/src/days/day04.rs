@@ -1,21 +1,24 @@
-use std::time::Instant;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use log::debug;
+pub const SOLUTION: &dyn SolveModule = &Day04;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day04;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day04 {
+    type Input = (i64, i64);
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(*input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(*input).to_string()
+    }
 }
 
 pub fn parse_input(file_contents: &str) -> (i64, i64) {
@@ -119,48 +122,19 @@ fn solve_part_2(input: (i64, i64)) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day04.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 8] = [
-            "1234-1234",
-            "1234567-1234567",
-            "122345-122345",
-            "111123-111123",
-            "135679-135679",
-            "111111-111111",
-            "223450-223450",
-            "123789-123789",
-        ];
-        let expected: [i64; 8] = [0, 0, 1, 1, 0, 1, 0, 0];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_1(parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 11] = [
-            "1234-1234",
-            "1234567-1234567",
-            "122345-122345",
-            "111123-111123",
-            "135679-135679",
-            "111111-111111",
-            "223450-223450",
-            "123789-123789",
-            "112233-112233",
-            "123444-123444",
-            "111122-111122",
-        ];
-        let expected: [i64; 11] = [0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 1];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_2(parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
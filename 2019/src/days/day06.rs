@@ -1,23 +1,26 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use log::debug;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use std::time::Instant;
+pub const SOLUTION: &dyn SolveModule = &Day06;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day06;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day06 {
+    type Input = HashMap<String, Planet>;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> HashMap<String, Planet> {
@@ -123,49 +126,19 @@ struct Planet {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day06.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["COM)B
-B)C
-C)D
-D)E
-E)F
-B)G
-G)H
-D)I
-E)J
-J)K
-K)L"];
-        let expected: [i64; 1] = [42];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_1(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["COM)B
-B)C
-C)D
-D)E
-E)F
-B)G
-G)H
-D)I
-E)J
-J)K
-K)L
-K)YOU
-I)SAN"];
-        let expected: [i64; 1] = [4];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_2(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
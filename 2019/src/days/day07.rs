@@ -1,26 +1,30 @@
 use std::collections::VecDeque;
-use std::time::Instant;
 
-use aoc_helpers::io::parse_int_list;
+use aoc_helpers::io::{ParseError, try_parse_int_list};
 use aoc_helpers::permutations::generate_permutations;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
 use crate::shared::intcode::{IntCodeComputer, IntCodeStatus};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, ',');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day07;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day07;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day07 {
+    type Input = Vec<i64>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, ',')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &[i64]) -> i64 {
@@ -110,34 +114,19 @@ fn solve_part_2(input: &[i64]) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day07.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 3] = [
-            "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0",
-            "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0",
-            "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0",
-        ];
-        let expected: [i64; 3] = [43210, 54321, 65210];
-
-        for i in 0..input.len() {
-            let parsed = parse_int_list(input[i], ',');
-            assert_eq!(solve_part_1(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 2] = [
-            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
-            "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10",
-        ];
-        let expected: [i64; 2] = [139629729, 18216];
-
-        for i in 0..input.len() {
-            let parsed = parse_int_list(input[i], ',');
-            assert_eq!(solve_part_2(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,24 +1,38 @@
-use std::time::Instant;
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use log::debug;
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let width = 25;
-    let height = 6;
+pub const SOLUTION: &dyn SolveModule = &Day08;
 
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents, width, height);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day08;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day08 {
+    type Input = (Vec<Layer>, usize, usize);
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, width, height);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        let width = params.get_or("width", WIDTH);
+        let height = params.get_or("height", HEIGHT);
+        Ok((parse_input(file_contents, width, height), width, height))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1, input.2)
+    }
 }
 
 fn parse_input(file_contents: &str, width: usize, height: usize) -> Vec<Layer> {
@@ -119,31 +133,19 @@ struct Layer {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day08.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["123456789012"];
-        let expected: [i64; 1] = [1];
-
-        let width = 3;
-        let height = 2;
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i], width, height);
-            assert_eq!(solve_part_1(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["0222112222120000"];
-        let expected: [&str; 1] = ["\n.#\n#."];
-
-        let width = 2;
-        let height = 2;
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i], width, height);
-            assert_eq!(solve_part_2(&parsed, width, height), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
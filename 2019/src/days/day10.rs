@@ -1,25 +1,33 @@
 use std::collections::{HashSet, VecDeque};
 use std::f64::consts::PI;
-use std::time::Instant;
 
+use aoc_helpers::io::ParseError;
 use aoc_helpers::math::greatest_common_divisor;
 use aoc_helpers::point2d::Point2d;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day10;
 
-    let part1_timer = Instant::now();
-    let (part1, origin) = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day10;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, &origin);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day10 {
+    type Input = Vec<Point2d>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        let (visible_count, _) = solve_part_1(input);
+        visible_count.to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        // The monitoring station is placed at the location found in part 1
+        let (_, origin) = solve_part_1(input);
+        solve_part_2(input, &origin).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Point2d> {
@@ -218,105 +226,19 @@ struct PolarCoordinate {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day10.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 5] = [
-            ".#..#
-.....
-#####
-....#
-...##",
-            "......#.#.
-#..#.#....
-..#######.
-.#.#.###..
-.#..#.....
-..#....#.#
-#..#....#.
-.##.#..###
-##...#..#.
-.#....####",
-            "#.#...#.#.
-.###....#.
-.#....#...
-##.#.#.#.#
-....#.#.#.
-.##..###.#
-..#...##..
-..##....##
-......#...
-.####.###.",
-            ".#..#..###
-####.###.#
-....###.#.
-..###.##.#
-##.##.#.#.
-....###..#
-..#.#..#.#
-#..#.#.###
-.##...##.#
-.....#.#..",
-            ".#..##.###...#######
-##.############..##.
-.#.######.########.#
-.###.#######.####.#.
-#####.##.#.##.###.##
-..#####..#.#########
-####################
-#.####....###.#.#.##
-##.#################
-#####.##.###..####..
-..######..##.#######
-####.##.####...##..#
-.#####..#.######.###
-##...#.##########...
-#.##########.#######
-.####.#.###.###.#.##
-....##.##.###..#####
-.#.#.###########.###
-#.#.#.#####.####.###
-###.##.####.##.#..##",
-        ];
-        let expected: [usize; 5] = [8, 33, 35, 41, 210];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_1(&parsed).0, expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [(Point2d, &str); 1] = [(
-            Point2d::new(11, 13),
-            ".#..##.###...#######
-##.############..##.
-.#.######.########.#
-.###.#######.####.#.
-#####.##.#.##.###.##
-..#####..#.#########
-####################
-#.####....###.#.#.##
-##.#################
-#####.##.###..####..
-..######..##.#######
-####.##.####...##..#
-.#####..#.######.###
-##...#.##########...
-#.##########.#######
-.####.#.###.###.#.##
-....##.##.###..#####
-.#.#.###########.###
-#.#.#.#####.####.###
-###.##.####.##.#..##",
-        )];
-        let expected: [i64; 1] = [802];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i].1);
-            assert_eq!(solve_part_2(&parsed, &input[i].0), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
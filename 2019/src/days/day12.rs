@@ -1,25 +1,39 @@
 use std::cmp::Ordering;
 use std::fmt;
-use std::time::Instant;
 
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::ParseError;
 use aoc_helpers::math::least_common_multiple;
 use aoc_helpers::point3d::Point3d;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day12;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input, 1000);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day12;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day12 {
+    type Input = (Vec<Moon>, i64);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((parse_input(file_contents), params.get_or("steps", 1000)))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, input.1).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Moon> {
@@ -200,51 +214,19 @@ impl fmt::Display for Moon {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day12.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [(&str, i64); 2] = [
-            (
-                "<x=-1, y=0, z=2>
-<x=2, y=-10, z=-7>
-<x=4, y=-8, z=8>
-<x=3, y=5, z=-1>",
-                10,
-            ),
-            (
-                "<x=-8, y=-10, z=0>
-<x=5, y=5, z=10>
-<x=2, y=-7, z=3>
-<x=9, y=-8, z=-3>",
-                100,
-            ),
-        ];
-        let expected: [i64; 2] = [179, 1940];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i].0);
-            assert_eq!(solve_part_1(&parsed, input[i].1), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 2] = [
-            "<x=-1, y=0, z=2>
-<x=2, y=-10, z=-7>
-<x=4, y=-8, z=8>
-<x=3, y=5, z=-1>",
-            "<x=-8, y=-10, z=0>
-<x=5, y=5, z=10>
-<x=2, y=-7, z=3>
-<x=9, y=-8, z=-3>",
-        ];
-        let expected: [i64; 2] = [2772, 4686774924];
-
-        for i in 0..input.len() {
-            let parsed = parse_input(input[i]);
-            assert_eq!(solve_part_2(&parsed), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
@@ -1,27 +1,34 @@
 use std::collections::{HashSet, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
-use aoc_helpers::io::parse_int_list;
+use aoc_helpers::io::{ParseError, try_parse_int_list};
 use aoc_helpers::point2d::Point2d;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
 use crate::shared::intcode::{IntCodeComputer, IntCodeStatus};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, ',');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day15;
 
-    let part1_timer = Instant::now();
-    let (part1, droid) = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day15;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(droid);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day15 {
+    type Input = Vec<i64>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, ',')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        let (steps, _) = solve_part_1(input);
+        steps.to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        // The oxygen spreads from the oxygen system found by the droid in part 1
+        let (_, droid) = solve_part_1(input);
+        solve_part_2(droid).to_string()
+    }
 }
 
 fn solve_part_1(input: &[i64]) -> (usize, Droid) {
@@ -1,26 +1,30 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::graph::{Edge, Node};
-use aoc_helpers::io::parse_char_grid;
+use aoc_helpers::io::{ParseError, parse_char_grid};
 use aoc_helpers::point2d::Point2d;
 use aoc_helpers::priority_queue::{PriorityQueue, PriorityQueueItem};
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_char_grid(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day18;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day18;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day18 {
+    type Input = HashMap<Point2d, char>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_char_grid(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn convert_to_graph(grid: &HashMap<Point2d, char>) -> HashMap<char, Node<char>> {
@@ -379,87 +383,19 @@ fn solve_part_2(grid: &HashMap<Point2d, char>) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day18.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 5] = [
-            "#########
-#b.A.@.a#
-#########",
-            "########################
-#f.D.E.e.C.b.A.@.a.B.c.#
-######################.#
-#d.....................#
-########################",
-            "########################
-#...............b.C.D.f#
-#.######################
-#.....@.a.B.c.d.A.e.F.g#
-########################",
-            "#################
-#i.G..c...e..H.p#
-########.########
-#j.A..b...f..D.o#
-########@########
-#k.E..a...g..B.n#
-########.########
-#l.F..d...h..C.m#
-#################",
-            "########################
-#@..............ac.GI.b#
-###d#e#f################
-###A#B#C################
-###g#h#i################
-########################",
-        ];
-        let expected: [i64; 5] = [8, 86, 132, 136, 81];
-
-        for i in 0..input.len() {
-            let input = parse_char_grid(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 4] = [
-            "#######
-#a.#Cd#
-##...##
-##.@.##
-##...##
-#cB#Ab#
-#######",
-            "###############
-#d.ABC.#.....a#
-######...######
-######.@.######
-######...######
-#b.....#.....c#
-###############",
-            "#############
-#DcBa.#.GhKl#
-#.###...#I###
-#e#d#.@.#j#k#
-###C#...###J#
-#fEbA.#.FgHi#
-#############",
-            "#############
-#g#f.D#..h#l#
-#F###e#E###.#
-#dCba...BcIJ#
-#####.@.#####
-#nK.L...G...#
-#M###N#H###.#
-#o#m..#i#jk.#
-#############",
-        ];
-        let expected: [i64; 4] = [8, 24, 32, 72];
-
-        for i in 0..input.len() {
-            let input = parse_char_grid(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
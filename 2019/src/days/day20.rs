@@ -1,27 +1,32 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 use aoc_helpers::direction::Direction;
 use aoc_helpers::graph::{Edge, Node};
-use aoc_helpers::io::parse_char_grid;
+use aoc_helpers::io::{ParseError, parse_char_grid};
 use aoc_helpers::point2d::Point2d;
 use aoc_helpers::priority_queue::{PriorityQueue, PriorityQueueItem};
-use log::{debug, trace};
+use aoc_helpers::search::dijkstra;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day20;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day20;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day20 {
+    type Input = MazeInfo;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> MazeInfo {
@@ -225,51 +230,38 @@ fn solve_part_1(input: &MazeInfo) -> i64 {
         input.start, input.end
     );
 
-    let mut queue: PriorityQueue<PriorityQueueItem<Point2d>> = PriorityQueue::new();
-    let mut visited: HashSet<Point2d> = HashSet::new();
-
-    queue.push(PriorityQueueItem::new(0, input.start));
-
-    while let Some(PriorityQueueItem {
-        weight: steps,
-        data: point,
-    }) = queue.pop()
-    {
-        trace!("Current point: {} ({})", point, steps);
-
-        if point == input.end {
-            trace!("found end after {} steps", steps);
-            return steps;
-        }
-
-        if visited.contains(&point) {
-            trace!("Already visited {}", point);
-            continue;
-        }
-        visited.insert(point);
-
-        if let Some(reachable_portals) = input.graph.get(&point) {
-            for portal in &reachable_portals.edges {
-                trace!(
-                    "found portal at {} with weight {}",
-                    portal.value, portal.weight
-                );
-
-                // enter portal
-                if let Some(&portal_exit) = input.portals.get(&portal.value) {
-                    trace!("can teleport to {} from {}", portal_exit, portal.value);
-                    queue.push(PriorityQueueItem::new(
-                        steps + portal.weight + 1,
-                        portal_exit,
-                    ));
-                } else {
-                    trace!("there is no exit to this portal");
-                    queue.push(PriorityQueueItem::new(steps + portal.weight, portal.value));
+    let result = dijkstra(
+        input.start,
+        |point| *point == input.end,
+        |point| {
+            let mut next: Vec<(Point2d, i64)> = Vec::new();
+            if let Some(reachable_portals) = input.graph.get(point) {
+                for portal in &reachable_portals.edges {
+                    trace!(
+                        "found portal at {} with weight {}",
+                        portal.value, portal.weight
+                    );
+
+                    // enter portal
+                    if let Some(&portal_exit) = input.portals.get(&portal.value) {
+                        trace!("can teleport to {} from {}", portal_exit, portal.value);
+                        next.push((portal_exit, portal.weight + 1));
+                    } else {
+                        trace!("there is no exit to this portal");
+                        next.push((portal.value, portal.weight));
+                    }
                 }
             }
+            next
+        },
+    );
+    match result {
+        Some(result) => {
+            trace!("found end after {} steps", result.distance);
+            result.distance
         }
+        None => unreachable!(),
     }
-    unreachable!();
 }
 
 fn solve_part_2(input: &MazeInfo) -> i64 {
@@ -375,141 +367,19 @@ struct MazeInfo {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day20.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 2] = [
-            "         A
-         A
-  #######.#########
-  #######.........#
-  #######.#######.#
-  #######.#######.#
-  #######.#######.#
-  #####  B    ###.#
-BC...##  C    ###.#
-  ##.##       ###.#
-  ##...DE  F  ###.#
-  #####    G  ###.#
-  #########.#####.#
-DE..#######...###.#
-  #.#########.###.#
-FG..#########.....#
-  ###########.#####
-             Z
-             Z       ",
-            "                   A
-                   A
-  #################.#############
-  #.#...#...................#.#.#
-  #.#.#.###.###.###.#########.#.#
-  #.#.#.......#...#.....#.#.#...#
-  #.#########.###.#####.#.#.###.#
-  #.............#.#.....#.......#
-  ###.###########.###.#####.#.#.#
-  #.....#        A   C    #.#.#.#
-  #######        S   P    #####.#
-  #.#...#                 #......VT
-  #.#.#.#                 #.#####
-  #...#.#               YN....#.#
-  #.###.#                 #####.#
-DI....#.#                 #.....#
-  #####.#                 #.###.#
-ZZ......#               QG....#..AS
-  ###.###                 #######
-JO..#.#.#                 #.....#
-  #.#.#.#                 ###.#.#
-  #...#..DI             BU....#..LF
-  #####.#                 #.#####
-YN......#               VT..#....QG
-  #.###.#                 #.###.#
-  #.#...#                 #.....#
-  ###.###    J L     J    #.#.###
-  #.....#    O F     P    #.#...#
-  #.###.#####.#.#####.#####.###.#
-  #...#.#.#...#.....#.....#.#...#
-  #.#####.###.###.#.#.#########.#
-  #...#.#.....#...#.#.#.#.....#.#
-  #.###.#####.###.###.#.#.#######
-  #.#.........#...#.............#
-  #########.###.###.#############
-           B   J   C
-           U   P   P               ",
-        ];
-        let expected: [i64; 2] = [23, 58];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 2] = [
-            "         A
-         A
-  #######.#########
-  #######.........#
-  #######.#######.#
-  #######.#######.#
-  #######.#######.#
-  #####  B    ###.#
-BC...##  C    ###.#
-  ##.##       ###.#
-  ##...DE  F  ###.#
-  #####    G  ###.#
-  #########.#####.#
-DE..#######...###.#
-  #.#########.###.#
-FG..#########.....#
-  ###########.#####
-             Z
-             Z       ",
-            "             Z L X W       C
-             Z P Q B       K
-  ###########.#.#.#.#######.###############
-  #...#.......#.#.......#.#.......#.#.#...#
-  ###.#.#.#.#.#.#.#.###.#.#.#######.#.#.###
-  #.#...#.#.#...#.#.#...#...#...#.#.......#
-  #.###.#######.###.###.#.###.###.#.#######
-  #...#.......#.#...#...#.............#...#
-  #.#########.#######.#.#######.#######.###
-  #...#.#    F       R I       Z    #.#.#.#
-  #.###.#    D       E C       H    #.#.#.#
-  #.#...#                           #...#.#
-  #.###.#                           #.###.#
-  #.#....OA                       WB..#.#..ZH
-  #.###.#                           #.#.#.#
-CJ......#                           #.....#
-  #######                           #######
-  #.#....CK                         #......IC
-  #.###.#                           #.###.#
-  #.....#                           #...#.#
-  ###.###                           #.#.#.#
-XF....#.#                         RF..#.#.#
-  #####.#                           #######
-  #......CJ                       NM..#...#
-  ###.#.#                           #.###.#
-RE....#.#                           #......RF
-  ###.###        X   X       L      #.#.#.#
-  #.....#        F   Q       P      #.#.#.#
-  ###.###########.###.#######.#########.###
-  #.....#...#.....#.......#...#.....#.#...#
-  #####.#.###.#######.#######.###.###.#.#.#
-  #.......#.......#.#.#.#.#...#...#...#.#.#
-  #####.###.#####.#.#.#.#.###.###.#.###.###
-  #.......#.....#.#...#...............#...#
-  #############.#.#.###.###################
-               A O F   N
-               A A D   M                     ",
-        ];
-        let expected: [i64; 2] = [26, 396];
-
-        for i in 0..input.len() {
-            let input = parse_input(input[i]);
-            assert_eq!(solve_part_2(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
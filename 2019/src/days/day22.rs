@@ -1,23 +1,28 @@
 use std::collections::VecDeque;
 use std::mem::swap;
-use std::time::Instant;
 
-use log::{debug, trace};
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day22;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input, 10007);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day22;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, 119315717514047);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day22 {
+    type Input = Vec<Instruction>;
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input, 10007).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input, 119315717514047).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Instruction> {
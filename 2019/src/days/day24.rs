@@ -1,25 +1,41 @@
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
-use aoc_helpers::io::parse_char_grid;
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::{ParseError, parse_char_grid};
 use aoc_helpers::point2d::Point2d;
 use aoc_helpers::point3d::Point3d;
-use log::{debug, trace};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::trace;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_char_grid(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day24;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day24;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input, 200);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+impl Solution for Day24 {
+    type Input = (HashMap<Point2d, char>, i64);
 
-    (part1.to_string(), part2.to_string())
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
+
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((
+            parse_char_grid(file_contents),
+            params.get_or("minutes", 200),
+        ))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0, input.1).to_string()
+    }
 }
 
 fn solve_part_1(input: &HashMap<Point2d, char>) -> i64 {
@@ -378,35 +394,19 @@ fn get_neighbours(point: &Point3d) -> Vec<Point3d> {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day24.txt");
+
     #[test]
     fn test_part_1() {
-        let input: [&str; 1] = ["....#
-#..#.
-#..##
-..#..
-#...."];
-        let expected: [i64; 1] = [2129920];
-
-        for i in 0..input.len() {
-            let input = parse_char_grid(input[i]);
-            assert_eq!(solve_part_1(&input), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let input: [&str; 1] = ["....#
-#..#.
-#..##
-..#..
-#...."];
-        let expected: [i64; 1] = [99];
-
-        for i in 0..input.len() {
-            let input = parse_char_grid(input[i]);
-            assert_eq!(solve_part_2(&input, 10), expected[i]);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
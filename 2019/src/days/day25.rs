@@ -1,24 +1,32 @@
 use std::collections::{HashSet, VecDeque};
-use std::time::Instant;
 use std::{env, io};
 
 use aoc_helpers::direction::Direction;
-use aoc_helpers::io::parse_int_list;
+use aoc_helpers::io::{ParseError, try_parse_int_list};
 use aoc_helpers::point2d::Point2d;
-use log::{debug, info};
+use aoc_helpers::solve::{Solution, SolveModule};
+use log::info;
 
 use crate::shared::intcode::{IntCodeComputer, IntCodeDisplay, IntCodeStatus};
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_int_list(file_contents, ',');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+pub const SOLUTION: &dyn SolveModule = &Day25;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+struct Day25;
 
-    (part1.to_string(), "Merry Christmas!".to_string())
+impl Solution for Day25 {
+    type Input = Vec<i64>;
+
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_int_list(file_contents, ',')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, _input: &Self::Input) -> String {
+        "Merry Christmas!".to_string()
+    }
 }
 
 fn solve_part_1(input: &[i64]) -> String {
@@ -1,7 +1,8 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 use std::fmt;
 
 use aoc_helpers::point2d::Point2d;
+use aoc_helpers::sparse_grid::SparseGrid;
 use log::{error, trace};
 
 #[derive(Clone, Debug, PartialEq)]
@@ -276,61 +277,30 @@ impl IntCodeComputer {
 }
 
 pub struct IntCodeDisplay {
-    pub pixels: HashMap<Point2d, char>,
-    default_character: char,
+    pub pixels: SparseGrid<char>,
     pixel_position: Point2d,
 }
 
 impl fmt::Display for IntCodeDisplay {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Get boundaries
-        let mut min_x = i64::MAX;
-        let mut min_y = i64::MAX;
-        let mut max_x = i64::MIN;
-        let mut max_y = i64::MIN;
-
-        for point in self.pixels.keys() {
-            if point.x < min_x {
-                min_x = point.x;
-            }
-            if point.y < min_y {
-                min_y = point.y;
-            }
-            if point.x > max_x {
-                max_x = point.x;
-            }
-            if point.y > max_y {
-                max_y = point.y;
-            }
-        }
-
-        // Get values
-        let mut output = String::new();
-        for y in min_y..=max_y {
-            output.push('\n');
-            for x in min_x..=max_x {
-                let value = match self.pixels.get(&Point2d::new(x, y)) {
-                    Some(value) => *value,
-                    None => self.default_character,
-                };
-                output.push(value);
-            }
+        // Every row starts on a new line
+        if self.pixels.bounds().is_none() {
+            return Ok(());
         }
-        write!(f, "{}", output)
+        write!(f, "\n{}", self.pixels)
     }
 }
 
 impl IntCodeDisplay {
     pub fn new() -> Self {
         IntCodeDisplay {
-            pixels: HashMap::new(),
-            default_character: '.',
+            pixels: SparseGrid::new('.'),
             pixel_position: Point2d::new(0, 0),
         }
     }
 
     pub fn set_default_character(&mut self, character: char) {
-        self.default_character = character;
+        self.pixels.set_default(character);
     }
 
     pub fn write_character(&mut self, character: char) {
@@ -1,23 +1,25 @@
-use std::time::Instant;
-
-use aoc_helpers::io::parse_range_list;
+use aoc_helpers::io::{ParseError, try_parse_range_list};
 use aoc_helpers::range::Range;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
+
+pub const SOLUTION: &dyn SolveModule = &Day02;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_range_list(file_contents, ',');
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day02;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day02 {
+    type Input = Vec<Range>;
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        try_parse_range_list(file_contents, ',')
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
 fn solve_part_1(input: &[Range]) -> i64 {
@@ -94,35 +96,19 @@ const fn get_digits_count(number: i64) -> u32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day02.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [(
-            "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
-            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
-            824824821-824824827,2121212118-2121212124",
-            1_227_775_554,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_range_list(input, ',');
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
-            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
-            824824821-824824827,2121212118-2121212124",
-            4_174_379_265,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_range_list(input, ',');
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
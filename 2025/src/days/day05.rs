@@ -1,135 +1,68 @@
-use std::time::Instant;
+use aoc_helpers::io::{ParseError, try_parse_int_list, try_parse_range_list};
+use aoc_helpers::range::RangeSet;
+use aoc_helpers::solve::{Solution, SolveModule};
 
-use aoc_helpers::io::{parse_int_list, parse_range_list};
-use aoc_helpers::range::Range;
-use log::debug;
+pub const SOLUTION: &dyn SolveModule = &Day05;
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+struct Day05;
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+impl Solution for Day05 {
+    type Input = (RangeSet, Vec<i64>);
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
-
-    (part1.to_string(), part2.to_string())
-}
-
-fn parse_input(file_contents: &str) -> (Vec<Range>, Vec<i64>) {
-    if let Some((first, second)) = file_contents.split_once("\n\n") {
-        let ranges = parse_range_list(first, '\n');
-        let numbers = parse_int_list(second, '\n');
-        return (ranges, numbers);
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        parse_input(file_contents)
     }
-    unreachable!();
-}
 
-fn solve_part_1(input: &(Vec<Range>, Vec<i64>)) -> i64 {
-    let mut total = 0;
-
-    for &ingredient in &input.1 {
-        for range in &input.0 {
-            if ingredient >= range.start && ingredient <= range.end {
-                total += 1;
-                break;
-            }
-        }
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
     }
 
-    total
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
 }
 
-fn solve_part_2(input: &(Vec<Range>, Vec<i64>)) -> i64 {
-    let ranges = consolidate_ranges(&input.0);
-
-    let mut total = 0;
-    for range in ranges {
-        total += range.end - range.start + 1;
-    }
-    total
+fn parse_input(file_contents: &str) -> Result<(RangeSet, Vec<i64>), ParseError> {
+    let Some((first, second)) = file_contents.split_once("\n\n") else {
+        return Err(ParseError::at(
+            file_contents,
+            file_contents,
+            "expected ranges and ingredients separated by a blank line",
+        ));
+    };
+    let ranges = try_parse_range_list(first, '\n')?.into_iter().collect();
+    let numbers =
+        try_parse_int_list(second, '\n').map_err(|error| error.within(file_contents, second))?;
+    Ok((ranges, numbers))
 }
 
-/// Consolidate a list of ranges into the fewest number of ranges
-///
-/// ref: <https://rosettacode.org/wiki/Range_consolidation>
-fn consolidate_ranges(ranges: &[Range]) -> Vec<Range> {
-    // Sort the ranges by start
-    let mut sorted = ranges.to_owned();
-    sorted.sort_by(|a, b| a.start.cmp(&b.start));
-    let mut ranges = sorted.into_iter();
+fn solve_part_1(input: &(RangeSet, Vec<i64>)) -> usize {
+    input
+        .1
+        .iter()
+        .filter(|&&ingredient| input.0.contains(ingredient))
+        .count()
+}
 
-    let mut consolidated = Vec::new();
-    if let Some(mut combined_range) = ranges.next() {
-        // Compare the ranges and attempt to combine until we find ranges that do not overlap
-        for to_compare in ranges {
-            // Attempt to combine the ranges and keep to compare with the next ranges
-            // If the ranges do not overlap, push the previous range to the final list
-            // and keep the new range to compare with next ranges
-            combined_range = combined_range.consolidate(&to_compare).map_or_else(
-                || {
-                    consolidated.push(combined_range);
-                    to_compare
-                },
-                |result| result,
-            );
-        }
-        consolidated.push(combined_range);
-    }
-    consolidated
+fn solve_part_2(input: &(RangeSet, Vec<i64>)) -> i64 {
+    input.0.len()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day05.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [(
-            "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32",
-            3,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32",
-            14,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
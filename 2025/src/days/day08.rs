@@ -1,24 +1,38 @@
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
-
-use aoc_helpers::io::parse_int_list;
+use aoc_helpers::examples::Params;
+use aoc_helpers::io::{ParseError, parse_int_list};
 use aoc_helpers::point3d::Point3d;
-use log::debug;
+use aoc_helpers::solve::{Solution, SolveModule};
+use aoc_helpers::union_find::{KeyedDisjointSet, kruskal};
+
+pub const SOLUTION: &dyn SolveModule = &Day08;
+
+struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Connection>, usize);
 
-pub fn solve(file_contents: &str) -> (String, String) {
-    let parse_timer = Instant::now();
-    let input = parse_input(file_contents);
-    debug!("File parse: ({:?})", parse_timer.elapsed());
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Solution::try_parse_with(self, file_contents, &Params::new())
+    }
 
-    let part1_timer = Instant::now();
-    let part1 = solve_part_1(&input, 1000);
-    debug!("Part 1: {} ({:?})", part1, part1_timer.elapsed());
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        Ok((
+            parse_input(file_contents),
+            params.get_or("connections", 1000),
+        ))
+    }
 
-    let part2_timer = Instant::now();
-    let part2 = solve_part_2(&input);
-    debug!("Part 2: {} ({:?})", part2, part2_timer.elapsed());
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(&input.0, input.1).to_string()
+    }
 
-    (part1.to_string(), part2.to_string())
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(&input.0).to_string()
+    }
 }
 
 fn parse_input(file_contents: &str) -> Vec<Connection> {
@@ -58,90 +72,36 @@ fn parse_input(file_contents: &str) -> Vec<Connection> {
     connections
 }
 
-fn solve_part_1(connections: &Vec<Connection>, iterations: usize) -> i64 {
-    let mut circuits: HashMap<Point3d, i64> = HashMap::new();
-    let mut unique_circuit_id = 1;
+fn solve_part_1(connections: &[Connection], iterations: usize) -> i64 {
+    let mut circuits: KeyedDisjointSet<Point3d> = KeyedDisjointSet::new();
     for connection in connections {
-        if circuits
-            .insert(connection.source, unique_circuit_id)
-            .is_some()
-        {
-            unique_circuit_id += 1;
-        }
-        if circuits
-            .insert(connection.target, unique_circuit_id)
-            .is_some()
-        {
-            unique_circuit_id += 1;
-        }
+        circuits.insert(connection.source);
+        circuits.insert(connection.target);
     }
 
-    // Iterate over X number of connections
+    // Combine the junction boxes of X number of connections into a single circuit
     for Connection { source, target, .. } in connections.iter().take(iterations) {
-        // Combine the junction boxes into a single circuit unless they are already connected
-        if let Some(&source_id) = circuits.get(source)
-            && let Some(&target_id) = circuits.get(target)
-            && source_id != target_id
-        {
-            // Assign the source id to all junction boxes in the target circuit
-            circuits
-                .iter_mut()
-                .filter(|(_, v)| **v == target_id)
-                .for_each(|(_, v)| *v = source_id);
-        }
-    }
-
-    // Find the circuit sizes
-    let mut circuit_sizes: HashMap<i64, i64> = HashMap::new();
-    for (_, v) in circuits {
-        circuit_sizes.entry(v).and_modify(|v| *v += 1).or_insert(1);
+        circuits.union(*source, *target);
     }
 
-    let mut sizes: Vec<i64> = circuit_sizes.into_values().collect();
+    let mut sizes: Vec<usize> = circuits.component_sizes();
     sizes.sort_by(|a, b| b.cmp(a));
 
     // Return the product of the 3 largest circuit sizes
-    sizes.iter().take(3).product()
+    sizes.iter().take(3).product::<usize>() as i64
 }
 
-fn solve_part_2(connections: &Vec<Connection>) -> i64 {
-    let mut circuits: HashMap<Point3d, i64> = HashMap::new();
-    let mut unique_circuit_id = 1;
-    for connection in connections {
-        if circuits
-            .insert(connection.source, unique_circuit_id)
-            .is_some()
-        {
-            unique_circuit_id += 1;
-        }
-        if circuits
-            .insert(connection.target, unique_circuit_id)
-            .is_some()
-        {
-            unique_circuit_id += 1;
-        }
+fn solve_part_2(connections: &[Connection]) -> i64 {
+    // The last connection of the spanning tree is the one that connects all junction boxes
+    let tree = kruskal(
+        connections
+            .iter()
+            .map(|connection| (connection.source, connection.target, connection.distance)),
+    );
+    match tree.last() {
+        Some((source, target, _)) => source.x * target.x,
+        None => unreachable!(),
     }
-
-    // Iterate over all connections
-    for Connection { source, target, .. } in connections {
-        // Combine the junction boxes into a single circuit unless they are already connected
-        if let Some(&source_id) = circuits.get(source)
-            && let Some(&target_id) = circuits.get(target)
-            && source_id != target_id
-        {
-            // Assign the source id to all junction boxes in the target circuit
-            circuits
-                .iter_mut()
-                .filter(|(_, v)| **v == target_id)
-                .for_each(|(_, v)| *v = source_id);
-        }
-
-        // Break when all junction boxes are connected
-        if circuits.values().collect::<HashSet<_>>().len() == 1 {
-            return source.x * target.x;
-        }
-    }
-    unreachable!();
 }
 
 #[derive(Debug)]
@@ -153,69 +113,19 @@ struct Connection {
 
 #[cfg(test)]
 mod tests {
+    use aoc_helpers::examples::check_examples;
+
     use super::*;
 
+    const EXAMPLES: &str = include_str!("../../examples/day08.txt");
+
     #[test]
     fn test_part_1() {
-        let data: [(&str, i64); 1] = [(
-            "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689",
-            40,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_1(&input, 10), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 1);
     }
 
     #[test]
     fn test_part_2() {
-        let data: [(&str, i64); 1] = [(
-            "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689",
-            25272,
-        )];
-
-        for (input, expected) in data {
-            let input = parse_input(input);
-            assert_eq!(solve_part_2(&input), expected);
-        }
+        check_examples(SOLUTION, EXAMPLES, 2);
     }
 }
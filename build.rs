@@ -0,0 +1,35 @@
+use std::env;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+
+/// The package name prefix of the year crates
+const YEAR_CRATE: &str = "advent_of_code_";
+
+/// Generate the `YEARS` table of src/years.rs from the year crates the runner depends on, so a
+/// year crate is registered by adding it as a dependency
+fn main() {
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    let manifest = read_to_string("Cargo.toml").expect("Could not read Cargo.toml");
+    let mut years: Vec<u16> = manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter_map(|line| line.trim().strip_prefix(YEAR_CRATE))
+        .filter_map(|line| line.split([' ', '=']).next()?.parse::<u16>().ok())
+        .collect();
+    years.sort_unstable();
+
+    let entries: String = years
+        .iter()
+        .map(|year| format!("    ({year}, {YEAR_CRATE}{year}::days::DAYS),\n"))
+        .collect();
+    let table = format!(
+        "/// The days registered by each year crate\n\
+         pub const YEARS: &[(u16, &[DaySolution])] = &[\n{entries}];\n"
+    );
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    write(Path::new(&out_dir).join("years.rs"), table).expect("Could not write the YEARS table");
+}
@@ -0,0 +1,302 @@
+use std::str::FromStr;
+
+use super::solve::SolveModule;
+
+/// Named values an example uses in place of the ones for the real input, e.g. a smaller grid
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    values: Vec<(String, String)>,
+}
+
+impl Params {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.values.retain(|(existing, _)| existing != key);
+        self.values.push((key.to_string(), value.to_string()));
+    }
+
+    /// Get a parameter, returning `None` if it is missing or cannot be parsed
+    #[inline]
+    #[must_use]
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.values
+            .iter()
+            .find(|(existing, _)| existing == key)
+            .and_then(|(_, value)| value.parse::<T>().ok())
+    }
+
+    /// Get a parameter or the value used for the real input
+    #[inline]
+    #[must_use]
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+/// An example input from the puzzle description and the answers it should produce
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub params: Params,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    /// The expected answer for a part, if the example has one
+    #[inline]
+    #[must_use]
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether an answer matches the expected answer for a part
+    ///
+    /// Trailing line breaks are ignored as blocks in the examples file cannot end with one.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, part: u8, answer: &str) -> bool {
+        self.expected(part)
+            .is_some_and(|expected| answer.trim_end_matches('\n') == expected)
+    }
+}
+
+/// Parse the examples of a single day
+///
+/// Every example starts with a `--- example` line, optionally followed by a name as in
+/// `--- example: larger grid`. The other lines starting with `--- ` set a field of the
+/// current example, either inline or as a block made up of the following lines:
+///
+/// ```text
+/// --- example
+/// --- param steps: 10
+/// --- input
+/// <x=-1, y=0, z=2>
+/// <x=2, y=-10, z=-7>
+/// --- part1: 179
+/// ```
+///
+/// Blank lines at the end of a block are ignored.
+///
+/// # Errors
+///
+/// Will return `Err` with the line number if a line cannot be parsed
+pub fn parse_examples(file_contents: &str) -> Result<Vec<Example>, String> {
+    let mut examples: Vec<Example> = Vec::new();
+    // The field being filled by a block and the lines of the block so far
+    let mut block: Option<(String, Vec<&str>)> = None;
+
+    for (index, line) in file_contents
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+    {
+        let Some(directive) = line.strip_prefix("--- ") else {
+            match &mut block {
+                Some((_, lines)) => lines.push(line),
+                None if line.trim().is_empty() => {}
+                None => return Err(format!("line {}: expected a `--- ` directive", index + 1)),
+            }
+            continue;
+        };
+
+        if let Some((field, lines)) = block.take() {
+            set_field(&mut examples, &field, &join_block(&lines), index)?;
+        }
+
+        let (field, value) = match directive.split_once(':') {
+            Some((field, value)) => (field.trim(), Some(value.trim())),
+            None => (directive.trim(), None),
+        };
+        if field == "example" {
+            examples.push(Example {
+                name: value
+                    .map_or_else(|| format!("example {}", examples.len() + 1), str::to_string),
+                ..Example::default()
+            });
+            continue;
+        }
+        match value {
+            Some(value) => set_field(&mut examples, field, value, index + 1)?,
+            None => block = Some((field.to_string(), Vec::new())),
+        }
+    }
+
+    if let Some((field, lines)) = block.take() {
+        set_field(
+            &mut examples,
+            &field,
+            &join_block(&lines),
+            file_contents.lines().count(),
+        )?;
+    }
+    Ok(examples)
+}
+
+fn join_block(lines: &[&str]) -> String {
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |index| index + 1);
+    lines[..end].join("\n")
+}
+
+fn set_field(
+    examples: &mut [Example],
+    field: &str,
+    value: &str,
+    line_number: usize,
+) -> Result<(), String> {
+    let Some(example) = examples.last_mut() else {
+        return Err(format!(
+            "line {line_number}: `{field}` given before the first `--- example`"
+        ));
+    };
+
+    match field {
+        "input" => example.input = value.to_string(),
+        "part1" => example.part1 = Some(value.to_string()),
+        "part2" => example.part2 = Some(value.to_string()),
+        other => {
+            let Some(key) = other.strip_prefix("param ") else {
+                return Err(format!("line {line_number}: unknown field `{other}`"));
+            };
+            example.params.insert(key.trim(), value);
+        }
+    }
+    Ok(())
+}
+
+/// Run a solution against every example with an answer for the given part
+///
+/// Meant to be called from unit tests with the contents of the examples file of a day.
+///
+/// # Panics
+///
+/// Will panic if the examples cannot be parsed, the input of an example is malformed, none of
+/// them has an answer for the part or an answer does not match
+#[inline]
+pub fn check_examples(solution: &dyn SolveModule, file_contents: &str, part: u8) {
+    let examples = parse_examples(file_contents).unwrap_or_else(|error| panic!("{error}"));
+
+    let mut checked = 0;
+    for example in &examples {
+        if let Some(expected) = example.expected(part) {
+            let input = solution
+                .try_parse_with(&example.input, &example.params)
+                .unwrap_or_else(|error| panic!("{}: invalid input at {error}", example.name));
+            let actual = if part == 1 {
+                input.part1()
+            } else {
+                input.part2()
+            };
+            assert!(
+                example.matches(part, &actual),
+                "{} part {part}: expected `{expected}`, got `{actual}`",
+                example.name
+            );
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "No examples have an answer for part {part}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_examples() {
+        let input = "--- example
+--- param steps: 10
+--- input
+<x=-1, y=0, z=2>
+  <x=2, y=-10, z=-7>
+
+--- part1: 179
+--- part2
+#..#
+.##.
+
+--- example: second
+--- input: 1122
+--- part1: 3
+";
+        let mut params = Params::new();
+        params.insert("steps", "10");
+
+        assert_eq!(
+            parse_examples(input),
+            Ok(vec![
+                Example {
+                    name: "example 1".to_string(),
+                    input: "<x=-1, y=0, z=2>\n  <x=2, y=-10, z=-7>".to_string(),
+                    params,
+                    part1: Some("179".to_string()),
+                    part2: Some("#..#\n.##.".to_string()),
+                },
+                Example {
+                    name: "second".to_string(),
+                    input: "1122".to_string(),
+                    params: Params::new(),
+                    part1: Some("3".to_string()),
+                    part2: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_examples_errors() {
+        let data: [(&str, &str); 3] = [
+            (
+                "--- input: 1",
+                "line 1: `input` given before the first `--- example`",
+            ),
+            (
+                "--- example\n--- answer: 1",
+                "line 2: unknown field `answer`",
+            ),
+            ("1122\n--- example", "line 1: expected a `--- ` directive"),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(parse_examples(input), Err(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::new();
+        params.insert("size", "5");
+        params.insert("size", "7");
+
+        assert_eq!(params.get::<i64>("size"), Some(7));
+        assert_eq!(params.get_or("steps", 100), 100);
+        assert_eq!(params.get::<i64>("missing"), None);
+    }
+}
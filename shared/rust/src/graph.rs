@@ -1,4 +1,7 @@
-﻿#[derive(Debug)]
+﻿use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug)]
 pub struct Node<T: Clone> {
     pub edges: Vec<Edge<T>>,
 }
@@ -24,3 +27,23 @@ impl<T: Clone> Edge<T> {
         Self { value, weight }
     }
 }
+
+/// Create a graph with the direction of every edge reversed, keeping the weights
+#[inline]
+#[must_use]
+pub fn reverse<T: Clone + Eq + Hash>(graph: &HashMap<T, Node<T>>) -> HashMap<T, Node<T>> {
+    let mut reversed: HashMap<T, Node<T>> = graph
+        .keys()
+        .map(|value| (value.clone(), Node::new(Vec::new())))
+        .collect();
+    for (value, node) in graph {
+        for edge in &node.edges {
+            reversed
+                .entry(edge.value.clone())
+                .or_insert_with(|| Node::new(Vec::new()))
+                .edges
+                .push(Edge::new(value.clone(), edge.weight));
+        }
+    }
+    reversed
+}
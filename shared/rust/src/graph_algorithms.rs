@@ -0,0 +1,625 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use super::graph::Node;
+
+/// A graph with every node numbered, including nodes that only appear as the target of an edge
+struct IndexedGraph<T> {
+    nodes: Vec<T>,
+    indices: HashMap<T, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<T: Clone + Eq + Hash> IndexedGraph<T> {
+    fn new(graph: &HashMap<T, Node<T>>) -> Self {
+        let mut indexed = Self {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        };
+        for (value, node) in graph {
+            let from = indexed.index(value);
+            for edge in &node.edges {
+                let to = indexed.index(&edge.value);
+                indexed.edges[from].push(to);
+            }
+        }
+        indexed
+    }
+
+    fn index(&mut self, value: &T) -> usize {
+        if let Some(&index) = self.indices.get(value) {
+            return index;
+        }
+        self.nodes.push(value.clone());
+        self.edges.push(Vec::new());
+        self.indices.insert(value.clone(), self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    fn values(&self, indices: &[usize]) -> Vec<T> {
+        indices
+            .iter()
+            .map(|&index| self.nodes[index].clone())
+            .collect()
+    }
+}
+
+/// Kahn's algorithm run one node at a time, for scheduling nodes that take time to complete
+///
+/// An edge from `a` to `b` means `a` must be completed before `b` becomes available. When
+/// several nodes are available the one with the lowest key is taken first.
+pub struct TopologicalSort<T, K> {
+    graph: IndexedGraph<T>,
+    keys: Vec<K>,
+    /// Number of incomplete nodes each node is waiting for
+    waiting: Vec<usize>,
+    available: BinaryHeap<Reverse<(K, usize)>>,
+    completed: usize,
+}
+
+impl<T: Clone + Eq + Hash, K: Clone + Ord> TopologicalSort<T, K> {
+    #[inline]
+    #[must_use]
+    pub fn new<F>(graph: &HashMap<T, Node<T>>, key: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+    {
+        let graph = IndexedGraph::new(graph);
+        let keys: Vec<K> = graph.nodes.iter().map(key).collect();
+        let mut waiting: Vec<usize> = vec![0; graph.nodes.len()];
+        for &to in graph.edges.iter().flatten() {
+            waiting[to] += 1;
+        }
+        let available = (0..graph.nodes.len())
+            .filter(|&index| waiting[index] == 0)
+            .map(|index| Reverse((keys[index].clone(), index)))
+            .collect();
+        Self {
+            graph,
+            keys,
+            waiting,
+            available,
+            completed: 0,
+        }
+    }
+
+    /// Take the available node with the lowest key
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.available
+            .pop()
+            .map(|Reverse((_, index))| self.graph.nodes[index].clone())
+    }
+
+    /// Mark a node as completed, making available the nodes that were only waiting for it
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the node is not part of the graph
+    #[inline]
+    pub fn complete(&mut self, value: &T) {
+        let Some(&index) = self.graph.indices.get(value) else {
+            panic!("cannot complete a node that is not part of the graph");
+        };
+        self.completed += 1;
+        for &next in &self.graph.edges[index] {
+            self.waiting[next] -= 1;
+            if self.waiting[next] == 0 {
+                self.available
+                    .push(Reverse((self.keys[next].clone(), next)));
+            }
+        }
+    }
+
+    /// Check if every node of the graph was completed
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.completed == self.graph.nodes.len()
+    }
+}
+
+/// Order the nodes so every edge points from an earlier node to a later node, taking the
+/// node with the lowest key first whenever there is a choice
+///
+/// Returns `None` if the graph has a cycle.
+#[inline]
+pub fn topological_sort<T, K, F>(graph: &HashMap<T, Node<T>>, key: F) -> Option<Vec<T>>
+where
+    T: Clone + Eq + Hash,
+    K: Clone + Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut sort = TopologicalSort::new(graph, key);
+    let mut order: Vec<T> = Vec::new();
+    while let Some(value) = sort.pop() {
+        sort.complete(&value);
+        order.push(value);
+    }
+    sort.is_finished().then_some(order)
+}
+
+/// Group the nodes that can all reach each other using Tarjan's algorithm
+///
+/// The components are listed so that edges between them point from later components to
+/// earlier ones, the reverse of a topological order.
+#[inline]
+pub fn strongly_connected_components<T: Clone + Eq + Hash>(
+    graph: &HashMap<T, Node<T>>,
+) -> Vec<Vec<T>> {
+    let graph = IndexedGraph::new(graph);
+    let mut tarjan = Tarjan {
+        edges: &graph.edges,
+        order: vec![None; graph.nodes.len()],
+        low_link: vec![0; graph.nodes.len()],
+        on_stack: vec![false; graph.nodes.len()],
+        stack: Vec::new(),
+        visited: 0,
+        components: Vec::new(),
+    };
+    for index in 0..graph.nodes.len() {
+        if tarjan.order[index].is_none() {
+            tarjan.visit(index);
+        }
+    }
+    tarjan
+        .components
+        .iter()
+        .map(|component| graph.values(component))
+        .collect()
+}
+
+struct Tarjan<'a> {
+    edges: &'a [Vec<usize>],
+    /// The order in which each node was first visited
+    order: Vec<Option<usize>>,
+    /// The earliest visited node reachable from each node that is still on the stack
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    visited: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    /// Visit every node reachable from the start, using an explicit stack instead of
+    /// recursion so long chains cannot overflow the call stack
+    fn visit(&mut self, start: usize) {
+        self.discover(start);
+        // Each visited node with the position of the next edge to follow
+        let mut calls: Vec<(usize, usize)> = vec![(start, 0)];
+        while let Some((index, edge)) = calls.pop() {
+            if let Some(&next) = self.edges[index].get(edge) {
+                calls.push((index, edge + 1));
+                match self.order[next] {
+                    None => {
+                        self.discover(next);
+                        calls.push((next, 0));
+                    }
+                    Some(order) if self.on_stack[next] => {
+                        self.low_link[index] = self.low_link[index].min(order);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            if let Some(&(parent, _)) = calls.last() {
+                self.low_link[parent] = self.low_link[parent].min(self.low_link[index]);
+            }
+            // The node is the first visited node of its component
+            if Some(self.low_link[index]) == self.order[index] {
+                let mut component: Vec<usize> = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == index {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    fn discover(&mut self, index: usize) {
+        self.order[index] = Some(self.visited);
+        self.low_link[index] = self.visited;
+        self.visited += 1;
+        self.stack.push(index);
+        self.on_stack[index] = true;
+    }
+}
+
+/// Group the nodes that are connected by edges in either direction
+#[inline]
+pub fn connected_components<T: Clone + Eq + Hash>(graph: &HashMap<T, Node<T>>) -> Vec<Vec<T>> {
+    let graph = IndexedGraph::new(graph);
+    let mut neighbours: Vec<Vec<usize>> = graph.edges.clone();
+    for (from, edges) in graph.edges.iter().enumerate() {
+        for &to in edges {
+            neighbours[to].push(from);
+        }
+    }
+
+    let mut components: Vec<Vec<T>> = Vec::new();
+    let mut visited: Vec<bool> = vec![false; graph.nodes.len()];
+    for start in 0..graph.nodes.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut component: Vec<usize> = Vec::new();
+        let mut queue: VecDeque<usize> = VecDeque::from([start]);
+        while let Some(index) = queue.pop_front() {
+            component.push(index);
+            for &next in &neighbours[index] {
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        components.push(graph.values(&component));
+    }
+    components
+}
+
+/// Find the nodes of a cycle in the graph, in the order the edges visit them
+#[inline]
+pub fn find_cycle<T: Clone + Eq + Hash>(graph: &HashMap<T, Node<T>>) -> Option<Vec<T>> {
+    let graph = IndexedGraph::new(graph);
+    // Nodes that were fully explored without finding a cycle
+    let mut explored: Vec<bool> = vec![false; graph.nodes.len()];
+    let mut on_path: Vec<bool> = vec![false; graph.nodes.len()];
+    (0..graph.nodes.len())
+        .find_map(|start| find_cycle_from(&graph.edges, start, &mut explored, &mut on_path))
+        .map(|cycle| graph.values(&cycle))
+}
+
+/// Depth-first search from the start for an edge back to a node on the current path, using
+/// an explicit stack so long chains cannot overflow the call stack
+fn find_cycle_from(
+    edges: &[Vec<usize>],
+    start: usize,
+    explored: &mut [bool],
+    on_path: &mut [bool],
+) -> Option<Vec<usize>> {
+    if explored[start] {
+        return None;
+    }
+
+    // Each node of the current path with the position of the next edge to follow
+    let mut path: Vec<(usize, usize)> = vec![(start, 0)];
+    on_path[start] = true;
+    while let Some((index, edge)) = path.pop() {
+        let Some(&next) = edges[index].get(edge) else {
+            on_path[index] = false;
+            explored[index] = true;
+            continue;
+        };
+        path.push((index, edge + 1));
+        if on_path[next] {
+            let position = path.iter().position(|&(node, _)| node == next)?;
+            return Some(path[position..].iter().map(|&(node, _)| node).collect());
+        }
+        if !explored[next] {
+            on_path[next] = true;
+            path.push((next, 0));
+        }
+    }
+    None
+}
+
+/// Check if any node of the graph can reach itself
+#[inline]
+#[must_use]
+pub fn has_cycle<T: Clone + Eq + Hash>(graph: &HashMap<T, Node<T>>) -> bool {
+    find_cycle(graph).is_some()
+}
+
+/// Count the distinct paths from the start to the goal
+///
+/// Cycles are allowed as long as they cannot reach the goal.
+///
+/// # Panics
+///
+/// Will panic if a cycle lies on a path from the start to the goal, as there would be
+/// infinitely many paths
+#[inline]
+#[must_use]
+pub fn count_paths<T: Clone + Eq + Hash>(graph: &HashMap<T, Node<T>>, start: &T, goal: &T) -> u64 {
+    count_paths_through(graph, start, goal, &[])
+}
+
+/// Count the distinct paths from the start to the goal that visit every required node
+///
+/// # Panics
+///
+/// Will panic if a cycle lies on a path from the start to the goal, or if more than 64 nodes
+/// are required
+#[inline]
+#[must_use]
+pub fn count_paths_through<T: Clone + Eq + Hash>(
+    graph: &HashMap<T, Node<T>>,
+    start: &T,
+    goal: &T,
+    required: &[T],
+) -> u64 {
+    assert!(required.len() <= 64, "at most 64 nodes can be required");
+    let mut counter = PathCounter {
+        graph: IndexedGraph::new(graph),
+        required: Vec::new(),
+        all_required: 0,
+        reaches_goal: Vec::new(),
+        counts: HashMap::new(),
+        in_progress: HashSet::new(),
+    };
+    let (Some(&start), Some(&goal)) = (
+        counter.graph.indices.get(start),
+        counter.graph.indices.get(goal),
+    ) else {
+        return u64::from(start == goal && required.iter().all(|value| value == start));
+    };
+    counter.required = vec![0; counter.graph.nodes.len()];
+    for (bit, value) in required.iter().enumerate() {
+        let Some(&index) = counter.graph.indices.get(value) else {
+            return 0;
+        };
+        counter.required[index] |= 1 << bit;
+        counter.all_required |= 1 << bit;
+    }
+    counter.reaches_goal = counter.find_reaches_goal(goal);
+    counter.count(start, goal)
+}
+
+struct PathCounter<T> {
+    graph: IndexedGraph<T>,
+    /// The bit of each required node, 0 for other nodes
+    required: Vec<u64>,
+    all_required: u64,
+    /// Whether each node has a path to the goal, other nodes add no paths
+    reaches_goal: Vec<bool>,
+    /// Paths to the goal from each node after visiting the given required nodes
+    counts: HashMap<(usize, u64), u64>,
+    in_progress: HashSet<(usize, u64)>,
+}
+
+impl<T> PathCounter<T> {
+    fn find_reaches_goal(&self, goal: usize) -> Vec<bool> {
+        let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); self.graph.nodes.len()];
+        for (from, edges) in self.graph.edges.iter().enumerate() {
+            for &to in edges {
+                incoming[to].push(from);
+            }
+        }
+
+        let mut reaches_goal: Vec<bool> = vec![false; self.graph.nodes.len()];
+        reaches_goal[goal] = true;
+        let mut queue: VecDeque<usize> = VecDeque::from([goal]);
+        while let Some(index) = queue.pop_front() {
+            for &previous in &incoming[index] {
+                if !reaches_goal[previous] {
+                    reaches_goal[previous] = true;
+                    queue.push_back(previous);
+                }
+            }
+        }
+        reaches_goal
+    }
+
+    /// Count the paths with a depth-first search over the node and the required nodes visited
+    /// so far, using an explicit stack so long chains cannot overflow the call stack
+    fn count(&mut self, start: usize, goal: usize) -> u64 {
+        let root = (start, self.required[start]);
+        if start == goal {
+            return u64::from(root.1 == self.all_required);
+        }
+
+        // Each state being counted with the position of the next edge and the paths so far
+        let mut stack: Vec<((usize, u64), usize, u64)> = vec![(root, 0, 0)];
+        self.in_progress.insert(root);
+        while let Some((state, edge, mut total)) = stack.pop() {
+            let (index, visited) = state;
+            let Some(&next) = self.graph.edges[index].get(edge) else {
+                self.in_progress.remove(&state);
+                self.counts.insert(state, total);
+                match stack.last_mut() {
+                    Some(parent) => parent.2 += total,
+                    None => return total,
+                }
+                continue;
+            };
+
+            let next_state = (next, visited | self.required[next]);
+            if !self.reaches_goal[next] {
+                // No path to the goal continues through this edge
+            } else if next == goal {
+                total += u64::from(next_state.1 == self.all_required);
+            } else if let Some(&count) = self.counts.get(&next_state) {
+                total += count;
+            } else {
+                assert!(
+                    self.in_progress.insert(next_state),
+                    "cannot count the paths of a graph with a cycle on a path to the goal"
+                );
+                stack.push((state, edge + 1, total));
+                stack.push((next_state, 0, 0));
+                continue;
+            }
+            stack.push((state, edge + 1, total));
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Edge;
+
+    type Edges<'a> = &'a [(char, char)];
+
+    fn create_graph(edges: Edges) -> HashMap<char, Node<char>> {
+        let mut graph: HashMap<char, Node<char>> = HashMap::new();
+        for &(from, to) in edges {
+            graph
+                .entry(from)
+                .or_insert_with(|| Node::new(Vec::new()))
+                .edges
+                .push(Edge::new(to, 1));
+        }
+        graph
+    }
+
+    fn sorted(mut components: Vec<Vec<char>>) -> Vec<String> {
+        for component in &mut components {
+            component.sort_unstable();
+        }
+        let mut components: Vec<String> = components
+            .into_iter()
+            .map(|component| component.into_iter().collect())
+            .collect();
+        components.sort_unstable();
+        components
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = create_graph(&[
+            ('C', 'A'),
+            ('C', 'F'),
+            ('A', 'B'),
+            ('A', 'D'),
+            ('B', 'E'),
+            ('D', 'E'),
+            ('F', 'E'),
+        ]);
+
+        let order = topological_sort(&graph, |&value| value).map(String::from_iter);
+        assert_eq!(order.as_deref(), Some("CABDFE"));
+        let order = topological_sort(&graph, |&value| Reverse(value)).map(String::from_iter);
+        assert_eq!(order.as_deref(), Some("CFADBE"));
+
+        let cyclic = create_graph(&[('a', 'b'), ('b', 'c'), ('c', 'b')]);
+        assert_eq!(topological_sort(&cyclic, |&value| value), None);
+    }
+
+    #[test]
+    fn test_scheduled_topological_sort() {
+        let graph = create_graph(&[('a', 'c'), ('b', 'c')]);
+        let mut sort = TopologicalSort::new(&graph, |&value| value);
+
+        assert_eq!(sort.pop(), Some('a'));
+        assert_eq!(sort.pop(), Some('b'));
+        sort.complete(&'a');
+        assert_eq!(sort.pop(), None);
+        sort.complete(&'b');
+        assert_eq!(sort.pop(), Some('c'));
+        assert!(!sort.is_finished());
+        sort.complete(&'c');
+        assert!(sort.is_finished());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = create_graph(&[
+            ('a', 'b'),
+            ('b', 'c'),
+            ('c', 'a'),
+            ('c', 'd'),
+            ('d', 'e'),
+            ('e', 'd'),
+            ('f', 'e'),
+        ]);
+        let components = strongly_connected_components(&graph);
+
+        assert_eq!(sorted(components.clone()), ["abc", "de", "f"]);
+        // Components that are reached from another component come first
+        let position = |value: char| components.iter().position(|c| c.contains(&value));
+        assert!(position('d') < position('a'));
+        assert!(position('d') < position('f'));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let graph = create_graph(&[('a', 'b'), ('c', 'b'), ('d', 'e'), ('f', 'f')]);
+        assert_eq!(sorted(connected_components(&graph)), ["abc", "de", "f"]);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let data: [(Edges, Option<usize>); 4] = [
+            (&[('a', 'b'), ('b', 'c')], None),
+            (&[('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd')], None),
+            (&[('a', 'b'), ('b', 'c'), ('c', 'a')], Some(3)),
+            (&[('a', 'b'), ('b', 'b')], Some(1)),
+        ];
+
+        for (edges, expected) in data {
+            let graph = create_graph(edges);
+            assert_eq!(find_cycle(&graph).map(|cycle| cycle.len()), expected);
+            assert_eq!(has_cycle(&graph), expected.is_some());
+        }
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = create_graph(&[
+            ('s', 'a'),
+            ('s', 'b'),
+            ('a', 'c'),
+            ('b', 'c'),
+            ('c', 'g'),
+            ('c', 'd'),
+            ('d', 'g'),
+            ('a', 'g'),
+        ]);
+        let data: [(&[char], u64); 5] = [
+            (&[], 5),
+            (&['c'], 4),
+            (&['a'], 3),
+            (&['a', 'd'], 1),
+            (&['x'], 0),
+        ];
+
+        for (required, expected) in data {
+            assert_eq!(count_paths_through(&graph, &'s', &'g', required), expected);
+        }
+        assert_eq!(count_paths(&graph, &'g', &'s'), 0);
+        assert_eq!(count_paths(&graph, &'g', &'g'), 1);
+
+        // Cycles that cannot reach the goal do not add any paths
+        let graph = create_graph(&[('s', 'g'), ('s', 'x'), ('x', 'y'), ('y', 'x')]);
+        assert_eq!(count_paths(&graph, &'s', &'g'), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle on a path to the goal")]
+    fn test_count_paths_cycle() {
+        let graph = create_graph(&[('s', 'x'), ('x', 'y'), ('y', 'x'), ('y', 'g')]);
+        let _ = count_paths(&graph, &'s', &'g');
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Deep enough to overflow the stack of a test thread if the searches recursed
+        const LENGTH: usize = 100_000;
+        let mut graph: HashMap<usize, Node<usize>> = (0..LENGTH)
+            .map(|index| (index, Node::new(vec![Edge::new(index + 1, 1)])))
+            .collect();
+
+        assert_eq!(count_paths(&graph, &0, &LENGTH), 1);
+        assert!(!has_cycle(&graph));
+        assert_eq!(strongly_connected_components(&graph).len(), LENGTH + 1);
+
+        graph.insert(LENGTH, Node::new(vec![Edge::new(0, 1)]));
+        assert_eq!(
+            find_cycle(&graph).map(|cycle| cycle.len()),
+            Some(LENGTH + 1)
+        );
+        assert_eq!(strongly_connected_components(&graph).len(), 1);
+    }
+}
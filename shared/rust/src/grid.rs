@@ -1,7 +1,26 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
 
+use super::io::{ParseError, check_rectangular};
 use super::point2d::Point2d;
 
+/// Offsets of the up, left, right and down neighbours, in the order of `Point2d::neighbours`
+const NEIGHBOUR_OFFSETS: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the neighbours including diagonals, in the order of `Point2d::neighbours8`
+const NEIGHBOUR8_OFFSETS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
 #[inline]
 #[must_use]
 pub fn get_dimensions(grid: &HashMap<Point2d, char>) -> (i64, i64) {
@@ -28,3 +47,494 @@ pub fn get_dimensions(grid: &HashMap<Point2d, char>) -> (i64, i64) {
 
     (max_x - min_x + 1, max_y - min_y + 1)
 }
+
+/// A rectangular grid stored row by row, indexed by points from `(0, 0)` at the top left
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid with every cell set to the given value
+    #[inline]
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from cells listed row by row
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the number of cells is not a multiple of the width
+    #[inline]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self, String> {
+        if width == 0 {
+            return if cells.is_empty() {
+                Ok(Self {
+                    width,
+                    height: 0,
+                    cells,
+                })
+            } else {
+                Err("Grid width must not be 0".to_string())
+            };
+        }
+        if !cells.len().is_multiple_of(width) {
+            return Err(format!(
+                "{} cells do not fill rows of {width} cells",
+                cells.len()
+            ));
+        }
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Create a grid from a list of rows
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the rows are not all the same length
+    #[inline]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "Row {y} has {} cells, expected {width}",
+                rows[y].len()
+            ));
+        }
+        Self::from_vec(width, rows.into_iter().flatten().collect())
+    }
+
+    /// Parse a rectangular grid of characters, converting each character into a cell
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the grid is not rectangular or `convert` rejects a character
+    #[inline]
+    pub fn try_parse_with<F>(file_contents: &str, mut convert: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        check_rectangular(file_contents)?;
+        let mut cells: Vec<T> = Vec::new();
+        let mut height = 0;
+        for line in file_contents.lines() {
+            for (i, c) in line.char_indices() {
+                let cell = convert(c).ok_or_else(|| {
+                    ParseError::at(
+                        file_contents,
+                        &line[i..i + c.len_utf8()],
+                        "unexpected character",
+                    )
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: cells.len() / height,
+            height,
+            cells,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Check if the point is inside the grid
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: &Point2d) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get(&self, point: &Point2d) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, point: &Point2d) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Move a point outside the grid back inside as if the grid repeats in every direction
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the grid is empty
+    #[inline]
+    #[must_use]
+    pub fn wrap(&self, point: &Point2d) -> Point2d {
+        assert!(
+            !self.cells.is_empty(),
+            "cannot wrap a point in an empty grid"
+        );
+        Point2d::new(
+            point.x.rem_euclid(self.width as i64),
+            point.y.rem_euclid(self.height as i64),
+        )
+    }
+
+    /// Get the cell at the point as if the grid repeats in every direction
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the grid is empty
+    #[inline]
+    #[must_use]
+    pub fn get_wrapping(&self, point: &Point2d) -> &T {
+        &self[self.wrap(point)]
+    }
+
+    /// Every point of the grid, row by row
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = Point2d> + use<T> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point2d::new(x, y)))
+    }
+
+    /// Every point of the grid with its cell, row by row
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Point2d, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The up, left, right and down neighbours of a point that are inside the grid
+    #[inline]
+    pub fn neighbours(&self, point: &Point2d) -> impl Iterator<Item = (Point2d, &T)> {
+        self.cells_around(*point, &NEIGHBOUR_OFFSETS)
+    }
+
+    /// The neighbours of a point including diagonals that are inside the grid
+    #[inline]
+    pub fn neighbours8(&self, point: &Point2d) -> impl Iterator<Item = (Point2d, &T)> {
+        self.cells_around(*point, &NEIGHBOUR8_OFFSETS)
+    }
+
+    /// The cells of a row from left to right
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the row is outside the grid
+    #[inline]
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row from top to bottom
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of a column from top to bottom
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the column is outside the grid
+    #[inline]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every column from left to right
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The first point, row by row, whose cell matches the predicate
+    #[inline]
+    pub fn find<P>(&self, mut predicate: P) -> Option<Point2d>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Every point, row by row, whose cell matches the predicate
+    #[inline]
+    pub fn find_all<P>(&self, mut predicate: P) -> impl Iterator<Item = Point2d>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The first point, row by row, holding the value
+    #[inline]
+    #[must_use]
+    pub fn position(&self, value: &T) -> Option<Point2d>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    /// Create a grid of the same size by converting every cell
+    #[inline]
+    #[must_use]
+    pub fn map<U, F>(&self, convert: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    fn index_of(&self, point: &Point2d) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn cells_around<'a, const N: usize>(
+        &'a self,
+        point: Point2d,
+        offsets: &'static [(i64, i64); N],
+    ) -> impl Iterator<Item = (Point2d, &'a T)> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        // Points away from the edges have every neighbour inside the grid
+        let inside = point.x > 0 && point.y > 0 && point.x < width - 1 && point.y < height - 1;
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (point.x + dx, point.y + dy);
+            (inside || ((0..width).contains(&x) && (0..height).contains(&y)))
+                .then(|| (Point2d::new(x, y), &self.cells[(y * width + x) as usize]))
+        })
+    }
+}
+
+impl<T> Index<Point2d> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Will panic if the point is outside the grid
+    #[inline]
+    fn index(&self, point: Point2d) -> &Self::Output {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{point} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point2d> for Grid<T> {
+    /// # Panics
+    ///
+    /// Will panic if the point is outside the grid
+    #[inline]
+    fn index_mut(&mut self, point: Point2d) -> &mut Self::Output {
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{point} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    /// Parse a rectangular grid of characters
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_parse_with(s, Some)
+    }
+}
+
+impl<T: Clone> From<&Grid<T>> for HashMap<Point2d, T> {
+    #[inline]
+    fn from(grid: &Grid<T>) -> Self {
+        grid.iter()
+            .map(|(point, cell)| (point, cell.clone()))
+            .collect()
+    }
+}
+
+impl<T: Clone> TryFrom<&HashMap<Point2d, T>> for Grid<T> {
+    type Error = String;
+
+    /// Convert a map covering every point from `(0, 0)` to its largest coordinates
+    #[inline]
+    fn try_from(map: &HashMap<Point2d, T>) -> Result<Self, Self::Error> {
+        if let Some(point) = map.keys().find(|point| point.x < 0 || point.y < 0) {
+            return Err(format!("{point} is outside the grid"));
+        }
+        let width = map.keys().map(|point| point.x + 1).max().unwrap_or(0);
+        let height = map.keys().map(|point| point.y + 1).max().unwrap_or(0);
+        let mut cells: Vec<T> = Vec::with_capacity(map.len());
+        for y in 0..height {
+            for x in 0..width {
+                let point = Point2d::new(x, y);
+                let cell = map
+                    .get(&point)
+                    .ok_or_else(|| format!("{point} is missing from the grid"))?;
+                cells.push(cell.clone());
+            }
+        }
+        Ok(Self {
+            width: width as usize,
+            height: height as usize,
+            cells,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::parse_char_grid;
+
+    const EXAMPLE: &str = "#.#.\n..@.\n#..#";
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid[Point2d::new(2, 1)], '@');
+        assert_eq!(grid.to_string(), EXAMPLE);
+        assert_eq!(
+            "#.\n#"
+                .parse::<Grid<char>>()
+                .map_err(|error| error.to_string()),
+            Err("line 2, column 1: expected a row of 2 characters, found `#`".to_string())
+        );
+        assert_eq!(
+            Grid::try_parse_with("01\n2x", |c| c.to_digit(10)).map_err(|error| error.to_string()),
+            Err("line 2, column 2: unexpected character, found `x`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        let data: [(Point2d, Option<char>, char); 5] = [
+            (Point2d::new(0, 0), Some('#'), '#'),
+            (Point2d::new(3, 2), Some('#'), '#'),
+            (Point2d::new(4, 0), None, '#'),
+            (Point2d::new(-1, 1), None, '.'),
+            (Point2d::new(-2, -1), None, '.'),
+        ];
+
+        for (point, expected, expected_wrapping) in data {
+            assert_eq!(grid.get(&point).copied(), expected);
+            assert_eq!(*grid.get_wrapping(&point), expected_wrapping);
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        let data: [(Point2d, usize, usize); 3] = [
+            (Point2d::new(0, 0), 2, 3),
+            (Point2d::new(1, 1), 4, 8),
+            (Point2d::new(3, 1), 3, 5),
+        ];
+
+        for (point, expected, expected8) in data {
+            assert_eq!(grid.neighbours(&point).count(), expected);
+            assert_eq!(grid.neighbours8(&point).count(), expected8);
+        }
+        assert_eq!(
+            grid.neighbours8(&Point2d::new(3, 1))
+                .filter(|(_, cell)| **cell == '#')
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.row(1), ['.', '.', '@', '.']);
+        assert_eq!(grid.column(3).collect::<String>(), "..#");
+        assert_eq!(grid.columns().count(), 4);
+        assert_eq!(grid.position(&'@'), Some(Point2d::new(2, 1)));
+        assert_eq!(grid.find(|cell| *cell == '?'), None);
+        assert_eq!(grid.find_all(|cell| *cell == '#').count(), 4);
+        assert_eq!(
+            grid.map(|cell| u8::from(*cell == '#')).to_string(),
+            "1010\n0000\n1001"
+        );
+    }
+
+    #[test]
+    fn test_hashmap_conversion() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        let map = parse_char_grid(EXAMPLE);
+
+        assert_eq!(HashMap::from(&grid), map);
+        assert_eq!(Grid::try_from(&map), Ok(grid));
+
+        let mut incomplete = map;
+        incomplete.remove(&Point2d::new(1, 1));
+        assert_eq!(
+            Grid::try_from(&incomplete),
+            Err("(1, 1) is missing from the grid".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).map(|grid| grid[Point2d::new(0, 1)]),
+            Ok(3)
+        );
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3]]),
+            Err("Row 1 has 1 cells, expected 2".to_string())
+        );
+        assert_eq!(
+            Grid::from_vec(2, vec![1, 2, 3]),
+            Err("3 cells do not fill rows of 2 cells".to_string())
+        );
+    }
+}
@@ -1,10 +1,91 @@
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::read_to_string;
-use std::io::Error;
+use std::io::{Error, Read, stdin};
 
 use super::point2d::Point2d;
 use super::range::Range;
 
+/// Malformed puzzle input, pointing at the text that could not be parsed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// Line of the offending text, starting from 1
+    pub line: usize,
+    /// Column of the offending text in characters, starting from 1
+    pub column: usize,
+    /// The text that could not be parsed
+    pub text: String,
+    /// What was expected instead
+    pub message: String,
+}
+
+impl ParseError {
+    /// Create an error for `text`, which must be a slice of `file_contents`
+    ///
+    /// Text that is not part of `file_contents` is reported at the start of the input.
+    #[inline]
+    #[must_use]
+    pub fn at(file_contents: &str, text: &str, message: &str) -> Self {
+        let (line, column) = get_position(file_contents, text);
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Move an error found in `section`, a slice of `file_contents`, to its position in
+    /// `file_contents`
+    #[inline]
+    #[must_use]
+    pub fn within(self, file_contents: &str, section: &str) -> Self {
+        let (line, column) = get_position(file_contents, section);
+        Self {
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            line: line + self.line - 1,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if self.text.is_empty() {
+            write!(f, ", found nothing")
+        } else {
+            write!(f, ", found `{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The line and column at which a slice of the input starts
+fn get_position(file_contents: &str, text: &str) -> (usize, usize) {
+    let start = file_contents.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|&offset| offset + text.len() <= file_contents.len())
+        .unwrap_or(0);
+    let before = &file_contents[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
 /// # Panics
 ///
 /// Will panic if the file does not exist
@@ -20,6 +101,21 @@ pub fn read_file(file_name: &str) -> Result<String, Error> {
     Ok(file_contents)
 }
 
+/// Read the whole of standard input, cleaned up the same way as `read_file`
+///
+/// # Errors
+///
+/// Will return `Err` if standard input cannot be read
+#[inline]
+pub fn read_stdin() -> Result<String, Error> {
+    let mut file_contents = String::new();
+    stdin().read_to_string(&mut file_contents)?;
+    Ok(file_contents
+        .trim_start_matches("\u{feff}")
+        .trim_end_matches('\n')
+        .to_string())
+}
+
 /// # Panics
 ///
 /// Will panic if input string is not an integer
@@ -31,6 +127,20 @@ pub fn parse_int(file_contents: &str) -> i64 {
         .expect("Input string is not a single number")
 }
 
+/// Parse an input that is a single integer, ignoring whitespace around it
+///
+/// # Errors
+///
+/// Will return `Err` if the input is not an integer
+#[inline]
+pub fn try_parse_int(file_contents: &str) -> Result<i64, ParseError> {
+    let value = file_contents.trim();
+    value
+        .parse::<i64>()
+        .map_err(|_| ParseError::at(file_contents, value, "expected a single integer"))
+}
+
+/// Skips values that are not integers, see `try_parse_int_list` to report them instead
 #[inline]
 #[must_use]
 pub fn parse_int_list(file_contents: &str, separator: char) -> Vec<i64> {
@@ -43,12 +153,48 @@ pub fn parse_int_list(file_contents: &str, separator: char) -> Vec<i64> {
     result
 }
 
+/// Parse a list of integers, ignoring whitespace around each value
+///
+/// # Errors
+///
+/// Will return `Err` at the first value that is not an integer, including empty values
+#[inline]
+pub fn try_parse_int_list(file_contents: &str, separator: char) -> Result<Vec<i64>, ParseError> {
+    file_contents
+        .split(separator)
+        .map(|value| {
+            let value = value.trim();
+            value
+                .parse::<i64>()
+                .map_err(|_| ParseError::at(file_contents, value, "expected an integer"))
+        })
+        .collect()
+}
+
 #[inline]
 #[must_use]
 pub fn parse_char_list(file_contents: &str) -> Vec<char> {
     file_contents.chars().collect()
 }
 
+/// Parse an input that is a single line of characters
+///
+/// # Errors
+///
+/// Will return `Err` if the input has more than one line
+#[inline]
+pub fn try_parse_char_list(file_contents: &str) -> Result<Vec<char>, ParseError> {
+    if let Some((_, rest)) = file_contents.split_once('\n') {
+        return Err(ParseError::at(
+            file_contents,
+            rest,
+            "expected a single line",
+        ));
+    }
+    Ok(parse_char_list(file_contents))
+}
+
+/// Skips values that are not ranges, see `try_parse_range_list` to report them instead
 #[inline]
 #[must_use]
 pub fn parse_range_list(file_contents: &str, separator: char) -> Vec<Range> {
@@ -64,6 +210,37 @@ pub fn parse_range_list(file_contents: &str, separator: char) -> Vec<Range> {
     result
 }
 
+/// Parse a list of inclusive ranges written as `start-end`, ignoring whitespace around each range
+///
+/// # Errors
+///
+/// Will return `Err` at the first value that is not a range
+#[inline]
+pub fn try_parse_range_list(
+    file_contents: &str,
+    separator: char,
+) -> Result<Vec<Range>, ParseError> {
+    file_contents
+        .split(separator)
+        .map(|value| {
+            let value = value.trim();
+            let Some((left, right)) = value.split_once('-') else {
+                return Err(ParseError::at(
+                    file_contents,
+                    value,
+                    "expected a range `start-end`",
+                ));
+            };
+            let parse = |bound: &str| {
+                bound
+                    .parse::<i64>()
+                    .map_err(|_| ParseError::at(file_contents, bound, "expected an integer"))
+            };
+            Ok(Range::new(parse(left)?, parse(right)?))
+        })
+        .collect()
+}
+
 #[inline]
 #[must_use]
 pub fn parse_char_grid(file_contents: &str) -> HashMap<Point2d, char> {
@@ -84,3 +261,143 @@ pub fn parse_char_vec(file_contents: &str) -> Vec<Vec<char>> {
         .map(|line| line.chars().collect())
         .collect()
 }
+
+/// Parse a rectangular grid of characters indexed by column and row
+///
+/// # Errors
+///
+/// Will return `Err` if the input is empty or a row is shorter or longer than the first row
+#[inline]
+pub fn try_parse_char_grid(file_contents: &str) -> Result<HashMap<Point2d, char>, ParseError> {
+    check_rectangular(file_contents)?;
+    Ok(parse_char_grid(file_contents))
+}
+
+/// Parse a rectangular grid of characters as a list of rows
+///
+/// # Errors
+///
+/// Will return `Err` if the input is empty or a row is shorter or longer than the first row
+#[inline]
+pub fn try_parse_char_vec(file_contents: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    check_rectangular(file_contents)?;
+    Ok(parse_char_vec(file_contents))
+}
+
+pub(crate) fn check_rectangular(file_contents: &str) -> Result<(), ParseError> {
+    let mut lines = file_contents.lines();
+    let width = match lines.next() {
+        Some(line) if !line.is_empty() => line.chars().count(),
+        _ => return Err(ParseError::at(file_contents, "", "expected a grid")),
+    };
+    for line in lines {
+        if line.chars().count() != width {
+            return Err(ParseError::at(
+                file_contents,
+                line,
+                &format!("expected a row of {width} characters"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_parse_int() {
+        assert_eq!(try_parse_int("-42"), Ok(-42));
+        assert_eq!(try_parse_int("42\n"), Ok(42));
+        assert_eq!(try_parse_int(" 7\r\n"), Ok(7));
+        assert_eq!(
+            try_parse_int("4x").map_err(|error| error.to_string()),
+            Err("line 1, column 1: expected a single integer, found `4x`".to_string())
+        );
+        assert_eq!(
+            try_parse_int(" 4x\n").map_err(|error| error.to_string()),
+            Err("line 1, column 2: expected a single integer, found `4x`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_try_parse_int_list() {
+        let data: [(&str, char, Vec<i64>); 2] = [
+            ("1,-2, 3", ',', vec![1, -2, 3]),
+            ("4\n5\r\n6", '\n', vec![4, 5, 6]),
+        ];
+
+        for (input, separator, expected) in data {
+            assert_eq!(try_parse_int_list(input, separator), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_try_parse_int_list_errors() {
+        let data: [(&str, char, &str); 3] = [
+            (
+                "1,2,",
+                ',',
+                "line 1, column 5: expected an integer, found nothing",
+            ),
+            (
+                "1\n2\n3a\n4",
+                '\n',
+                "line 3, column 1: expected an integer, found `3a`",
+            ),
+            (
+                "1\t2 3",
+                '\t',
+                "line 1, column 3: expected an integer, found `2 3`",
+            ),
+        ];
+
+        for (input, separator, expected) in data {
+            let result = try_parse_int_list(input, separator).map_err(|error| error.to_string());
+            assert_eq!(result, Err(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_try_parse_range_list() {
+        let data: [(&str, Result<Vec<Range>, &str>); 3] = [
+            ("1-3,10-20", Ok(vec![Range::new(1, 3), Range::new(10, 20)])),
+            (
+                "1-3,10",
+                Err("line 1, column 5: expected a range `start-end`, found `10`"),
+            ),
+            (
+                "1-3,10-2x",
+                Err("line 1, column 8: expected an integer, found `2x`"),
+            ),
+        ];
+
+        for (input, expected) in data {
+            let result = try_parse_range_list(input, ',').map_err(|error| error.to_string());
+            assert_eq!(result, expected.map_err(str::to_string));
+        }
+    }
+
+    #[test]
+    fn test_try_parse_char_grid() {
+        assert_eq!(try_parse_char_vec("ab\ncd").map(|rows| rows.len()), Ok(2));
+        assert_eq!(try_parse_char_grid("ab\ncd").map(|grid| grid.len()), Ok(4));
+        assert_eq!(
+            try_parse_char_grid("ab\nc\nde").map_err(|error| error.to_string()),
+            Err("line 2, column 1: expected a row of 2 characters, found `c`".to_string())
+        );
+        assert!(try_parse_char_list("ab\ncd").is_err());
+    }
+
+    #[test]
+    fn test_parse_error_within() {
+        let file_contents = "1-3\n4-5\n\n7\nx";
+        let (_, numbers) = file_contents.split_once("\n\n").unwrap();
+        let error = try_parse_int_list(numbers, '\n')
+            .unwrap_err()
+            .within(file_contents, numbers);
+
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+}
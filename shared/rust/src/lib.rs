@@ -1,5 +1,7 @@
 pub mod direction;
+pub mod examples;
 pub mod graph;
+pub mod graph_algorithms;
 pub mod grid;
 pub mod hex;
 pub mod io;
@@ -12,4 +14,8 @@ pub mod point3d;
 pub mod point4d;
 pub mod priority_queue;
 pub mod range;
+pub mod scan;
+pub mod search;
 pub mod solve;
+pub mod sparse_grid;
+pub mod union_find;
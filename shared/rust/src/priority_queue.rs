@@ -1,34 +1,543 @@
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::mem::{replace, take};
 
 pub type PriorityQueue<T> = BinaryHeap<T>;
 
-#[derive(Debug, Eq, PartialEq)]
-pub struct PriorityQueueItem<T: Eq> {
-    pub weight: i64,
+/// An item of a `PriorityQueue` that is popped lowest weight first
+///
+/// Items of equal weight are popped in no particular order, so the data does not need to be
+/// `Ord`. Use `OrderedPriorityQueueItem` when ties must be broken deterministically.
+#[derive(Debug)]
+pub struct PriorityQueueItem<T, W = i64> {
+    pub weight: W,
     pub data: T,
 }
 
-impl<T: Eq> Ord for PriorityQueueItem<T> {
+impl<T, W: Ord> Ord for PriorityQueueItem<T, W> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         other.weight.cmp(&self.weight)
     }
 }
 
-impl<T: Eq> PartialOrd for PriorityQueueItem<T> {
+impl<T, W: Ord> PartialOrd for PriorityQueueItem<T, W> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T: Eq> PriorityQueueItem<T> {
+impl<T, W: Eq> PartialEq for PriorityQueueItem<T, W> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.weight == other.weight
+    }
+}
+
+impl<T, W: Eq> Eq for PriorityQueueItem<T, W> {}
+
+impl<T, W> PriorityQueueItem<T, W> {
+    #[inline]
+    pub const fn new(weight: W, data: T) -> Self {
+        Self { weight, data }
+    }
+}
+
+/// An item of a `PriorityQueue` that is popped lowest weight first, then lowest data first
+#[derive(Debug, Eq, PartialEq)]
+pub struct OrderedPriorityQueueItem<T, W = i64> {
+    pub weight: W,
+    pub data: T,
+}
+
+impl<T: Ord, W: Ord> Ord for OrderedPriorityQueueItem<T, W> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .weight
+            .cmp(&self.weight)
+            .then_with(|| other.data.cmp(&self.data))
+    }
+}
+
+impl<T: Ord, W: Ord> PartialOrd for OrderedPriorityQueueItem<T, W> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, W> OrderedPriorityQueueItem<T, W> {
+    #[inline]
+    pub const fn new(weight: W, data: T) -> Self {
+        Self { weight, data }
+    }
+}
+
+/// A min-heap holding each key at most once, so the priority of a queued key can be changed
+/// instead of queueing it again
+///
+/// Each key is given an id the first time it is queued and the heap tracks ids, so moving
+/// items within the heap does not hash their keys. Ids are kept after a key is popped, so
+/// the memory used grows with the number of distinct keys ever queued.
+#[derive(Clone, Debug)]
+pub struct IndexedPriorityQueue<K, W> {
+    /// The id and priority of each queued key
+    heap: Vec<(usize, W)>,
+    /// The id of each key that was ever queued
+    ids: HashMap<K, usize>,
+    /// The key of each id
+    keys: Vec<K>,
+    /// The position of each id in the heap, `None` if the key is not queued
+    positions: Vec<Option<usize>>,
+}
+
+impl<K: Clone + Eq + Hash, W: Ord> Default for IndexedPriorityQueue<K, W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash, W: Ord> IndexedPriorityQueue<K, W> {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            ids: HashMap::new(),
+            keys: Vec::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// The priority of a queued key
+    #[inline]
+    #[must_use]
+    pub fn priority(&self, key: &K) -> Option<&W> {
+        self.position(key).map(|position| &self.heap[position].1)
+    }
+
+    /// The key with the lowest priority, without removing it
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> Option<(&K, &W)> {
+        self.heap
+            .first()
+            .map(|(id, priority)| (&self.keys[*id], priority))
+    }
+
+    /// Queue a key, or change its priority if it is already queued, returning the previous
+    /// priority
+    #[inline]
+    pub fn push(&mut self, key: K, priority: W) -> Option<W> {
+        let id = self.id(key);
+        if let Some(position) = self.positions[id] {
+            let previous = replace(&mut self.heap[position].1, priority);
+            self.restore(position);
+            return Some(previous);
+        }
+        self.heap.push((id, priority));
+        self.positions[id] = Some(self.heap.len() - 1);
+        self.sift_up(self.heap.len() - 1);
+        None
+    }
+
+    /// Lower the priority of a queued key, returning `false` if the key is not queued or
+    /// already has a lower or equal priority
+    #[inline]
+    pub fn decrease_key(&mut self, key: &K, priority: W) -> bool {
+        match self.position(key) {
+            Some(position) if priority < self.heap[position].1 => {
+                self.heap[position].1 = priority;
+                self.sift_up(position);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Queue a key that is not queued or lower its priority if it is, returning `false` if it
+    /// is already queued with a lower or equal priority
+    #[inline]
+    pub fn push_decrease(&mut self, key: K, priority: W) -> bool {
+        if self.contains(&key) {
+            self.decrease_key(&key, priority)
+        } else {
+            self.push(key, priority);
+            true
+        }
+    }
+
+    /// Remove the key with the lowest priority
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, W)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        Some(self.remove_at(0))
+    }
+
+    /// Remove a queued key, returning its priority
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<W> {
+        let position = self.position(key)?;
+        Some(self.remove_at(position).1)
+    }
+
+    /// The id of a key, giving it a new id if it was never queued
+    fn id(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.keys.push(key.clone());
+        self.positions.push(None);
+        self.ids.insert(key, id);
+        id
+    }
+
+    fn position(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).and_then(|&id| self.positions[id])
+    }
+
+    fn remove_at(&mut self, position: usize) -> (K, W) {
+        let last = self.heap.len() - 1;
+        self.swap(position, last);
+        let removed = self.heap.pop();
+        let Some((id, priority)) = removed else {
+            unreachable!("the heap cannot be empty");
+        };
+        self.positions[id] = None;
+        if position < self.heap.len() {
+            self.restore(position);
+        }
+        (self.keys[id].clone(), priority)
+    }
+
+    /// Move an item with a changed priority to its place in the heap
+    fn restore(&mut self, position: usize) {
+        let position = self.sift_up(position);
+        self.sift_down(position);
+    }
+
+    fn sift_up(&mut self, mut position: usize) -> usize {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if self.heap[position].1 >= self.heap[parent].1 {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+        position
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        loop {
+            let mut smallest = position;
+            for child in [2 * position + 1, 2 * position + 2] {
+                if child < self.heap.len() && self.heap[child].1 < self.heap[smallest].1 {
+                    smallest = child;
+                }
+            }
+            if smallest == position {
+                break;
+            }
+            self.swap(position, smallest);
+            position = smallest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a].0] = Some(a);
+        self.positions[self.heap[b].0] = Some(b);
+    }
+}
+
+/// A min-heap of integer priorities where no priority is lower than the last one popped, as
+/// in Dijkstra's algorithm with non-negative weights
+///
+/// Items are kept in buckets by the highest bit that differs from the last popped priority,
+/// so each item is only moved between buckets a few times.
+#[derive(Clone, Debug)]
+pub struct RadixHeap<T> {
+    buckets: Vec<Vec<(u64, T)>>,
+    last: u64,
+    len: usize,
+}
+
+impl<T> Default for RadixHeap<T> {
     #[inline]
-    pub const fn new(steps: i64, data: T) -> Self {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RadixHeap<T> {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
         Self {
-            weight: steps,
-            data,
+            buckets: (0..=u64::BITS).map(|_| Vec::new()).collect(),
+            last: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the priority is lower than the last popped priority
+    #[inline]
+    pub fn push(&mut self, priority: u64, data: T) {
+        assert!(
+            priority >= self.last,
+            "priority {priority} is lower than the last popped priority {}",
+            self.last
+        );
+        let bucket = self.bucket(priority);
+        self.buckets[bucket].push((priority, data));
+        self.len += 1;
+    }
+
+    /// Remove an item with the lowest priority
+    #[inline]
+    pub fn pop(&mut self) -> Option<(u64, T)> {
+        if self.buckets[0].is_empty() {
+            let index = self.buckets.iter().position(|bucket| !bucket.is_empty())?;
+            let bucket = take(&mut self.buckets[index]);
+            self.last = bucket
+                .iter()
+                .map(|(priority, _)| *priority)
+                .min()
+                .unwrap_or(self.last);
+            for (priority, data) in bucket {
+                let bucket = self.bucket(priority);
+                self.buckets[bucket].push((priority, data));
+            }
+        }
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+
+    fn bucket(&self, priority: u64) -> usize {
+        (u64::BITS - (priority ^ self.last).leading_zeros()) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use super::*;
+
+    /// A xorshift generator so the randomized tests are repeatable
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    #[test]
+    fn test_priority_queue_item() {
+        let mut queue: PriorityQueue<PriorityQueueItem<f64, u32>> = PriorityQueue::new();
+        for (weight, data) in [(3, 0.5), (1, 1.5), (2, 2.5)] {
+            queue.push(PriorityQueueItem::new(weight, data));
+        }
+
+        let order: Vec<(u32, f64)> = std::iter::from_fn(|| queue.pop())
+            .map(|item| (item.weight, item.data))
+            .collect();
+        assert_eq!(order, [(1, 1.5), (2, 2.5), (3, 0.5)]);
+    }
+
+    #[test]
+    fn test_ordered_priority_queue_item() {
+        let mut queue: PriorityQueue<OrderedPriorityQueueItem<char, u32>> = PriorityQueue::new();
+        for (weight, data) in [(3, 'a'), (1, 'c'), (1, 'b'), (2, 'a')] {
+            queue.push(OrderedPriorityQueueItem::new(weight, data));
+        }
+
+        let order: Vec<(u32, char)> = std::iter::from_fn(|| queue.pop())
+            .map(|item| (item.weight, item.data))
+            .collect();
+        assert_eq!(order, [(1, 'b'), (1, 'c'), (2, 'a'), (3, 'a')]);
+    }
+
+    #[test]
+    fn test_indexed_priority_queue() {
+        let mut queue: IndexedPriorityQueue<char, i64> = IndexedPriorityQueue::new();
+        for (key, priority) in [('a', 5), ('b', 3), ('c', 8), ('d', 1), ('e', 6)] {
+            queue.push(key, priority);
+        }
+
+        assert!(queue.decrease_key(&'c', 2));
+        assert!(!queue.decrease_key(&'a', 7));
+        assert!(!queue.decrease_key(&'x', 0));
+        assert!(!queue.push_decrease('e', 9));
+        assert!(queue.push_decrease('f', 4));
+        assert_eq!(queue.push('d', 10), Some(1));
+        assert_eq!(queue.remove(&'b'), Some(3));
+        assert_eq!(queue.remove(&'b'), None);
+        assert!(queue.contains(&'a'));
+        assert_eq!(queue.priority(&'f'), Some(&4));
+        assert_eq!(queue.peek(), Some((&'c', &2)));
+
+        let order: Vec<(char, i64)> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(order, [('c', 2), ('f', 4), ('a', 5), ('e', 6), ('d', 10)]);
+        assert!(queue.is_empty());
+
+        // Keys can be queued again after they were popped
+        assert_eq!(queue.push('c', 7), None);
+        assert!(!queue.contains(&'a'));
+        assert_eq!(queue.pop(), Some(('c', 7)));
+    }
+
+    #[test]
+    fn test_radix_heap() {
+        let mut heap: RadixHeap<char> = RadixHeap::new();
+        for (priority, data) in [(5, 'a'), (0, 'b'), (1000, 'c'), (7, 'd')] {
+            heap.push(priority, data);
+        }
+
+        assert_eq!(heap.pop(), Some((0, 'b')));
+        assert_eq!(heap.pop(), Some((5, 'a')));
+        heap.push(5, 'e');
+        heap.push(6, 'f');
+        assert_eq!(heap.len(), 4);
+
+        let order: Vec<(u64, char)> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(order, [(5, 'e'), (6, 'f'), (7, 'd'), (1000, 'c')]);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_indexed_priority_queue_random() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        let mut queue: IndexedPriorityQueue<u64, u64> = IndexedPriorityQueue::new();
+        // The reference queues duplicates and skips entries that no longer match a priority
+        let mut reference: BinaryHeap<Reverse<(u64, u64)>> = BinaryHeap::new();
+        let mut priorities: HashMap<u64, u64> = HashMap::new();
+
+        for _ in 0..20_000 {
+            let key = random.next(200);
+            let priority = random.next(1000);
+            match random.next(5) {
+                0 => {
+                    assert_eq!(queue.push(key, priority), priorities.insert(key, priority));
+                    reference.push(Reverse((priority, key)));
+                }
+                1 => {
+                    let lower = priorities.get(&key).is_some_and(|&old| priority < old);
+                    assert_eq!(queue.decrease_key(&key, priority), lower);
+                    if lower {
+                        priorities.insert(key, priority);
+                        reference.push(Reverse((priority, key)));
+                    }
+                }
+                2 => {
+                    let lower = priorities.get(&key).is_none_or(|&old| priority < old);
+                    assert_eq!(queue.push_decrease(key, priority), lower);
+                    if lower {
+                        priorities.insert(key, priority);
+                        reference.push(Reverse((priority, key)));
+                    }
+                }
+                3 => assert_eq!(queue.remove(&key), priorities.remove(&key)),
+                _ => {
+                    let expected = std::iter::from_fn(|| reference.pop())
+                        .find(|Reverse((priority, key))| priorities.get(key) == Some(priority));
+                    let popped = queue.pop();
+                    assert_eq!(
+                        popped.map(|(_, priority)| priority),
+                        expected.map(|Reverse((priority, _))| priority)
+                    );
+                    if let Some((key, priority)) = popped {
+                        assert_eq!(priorities.remove(&key), Some(priority));
+                        // Equal priorities may be popped in another order
+                        reference.retain(|Reverse(item)| *item != (priority, key));
+                        if let Some(Reverse((priority, key))) = expected
+                            && priorities.get(&key) == Some(&priority)
+                        {
+                            reference.push(Reverse((priority, key)));
+                        }
+                    }
+                }
+            }
+            assert_eq!(queue.len(), priorities.len());
+            assert_eq!(queue.is_empty(), priorities.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_radix_heap_random() {
+        let mut random = Random(0x9e37_79b9_7f4a_7c15);
+        let mut heap: RadixHeap<u64> = RadixHeap::new();
+        let mut reference: BinaryHeap<Reverse<(u64, u64)>> = BinaryHeap::new();
+        let mut last = 0;
+
+        for data in 0..20_000 {
+            if random.next(3) == 0 {
+                let popped = heap.pop();
+                let expected = reference.pop().map(|Reverse(item)| item);
+                assert_eq!(
+                    popped.map(|(priority, _)| priority),
+                    expected.map(|item| item.0)
+                );
+                if let (Some(popped), Some(expected)) = (popped, expected) {
+                    last = popped.0;
+                    // Equal priorities may be popped in another order
+                    if popped != expected {
+                        reference.retain(|Reverse(item)| *item != popped);
+                        reference.push(Reverse(expected));
+                    }
+                }
+            } else {
+                // Priorities are never lower than the last one popped
+                let bits = random.next(20);
+                let priority = last + random.next(1 << bits);
+                heap.push(priority, data);
+                reference.push(Reverse((priority, data)));
+            }
+            assert_eq!(heap.len(), reference.len());
         }
     }
 }
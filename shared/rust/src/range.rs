@@ -20,6 +20,44 @@ impl Range {
         Self { start, end }
     }
 
+    /// Create an inclusive range from a half-open range `start..end`
+    #[inline]
+    #[must_use]
+    pub const fn from_half_open(start: i64, end: i64) -> Self {
+        Self::new(start, end - 1)
+    }
+
+    /// Convert the inclusive range to the equivalent half-open range `start..end + 1`
+    #[inline]
+    #[must_use]
+    pub const fn to_half_open(&self) -> std::ops::Range<i64> {
+        self.start..self.end + 1
+    }
+
+    /// The number of values in the range
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> i64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end - self.start + 1
+        }
+    }
+
+    /// Check if the range contains no values, i.e. its end is before its start
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
     /// Attempt to combine two ranges of numbers
     ///
     /// # Examples
@@ -57,6 +95,246 @@ impl Range {
     }
 }
 
+impl From<std::ops::Range<i64>> for Range {
+    #[inline]
+    fn from(range: std::ops::Range<i64>) -> Self {
+        Self::from_half_open(range.start, range.end)
+    }
+}
+
+impl From<std::ops::RangeInclusive<i64>> for Range {
+    #[inline]
+    fn from(range: std::ops::RangeInclusive<i64>) -> Self {
+        Self::new(*range.start(), *range.end())
+    }
+}
+
+impl From<Range> for std::ops::RangeInclusive<i64> {
+    #[inline]
+    fn from(range: Range) -> Self {
+        range.start..=range.end
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent inclusive ranges
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RangeSet {
+    ranges: Vec<Range>,
+}
+
+impl fmt::Display for RangeSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(ToString::to_string).collect();
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+impl RangeSet {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// The normalized ranges of the set, sorted by start
+    #[inline]
+    #[must_use]
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// The total number of values covered by the set
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(Range::len).sum()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest range containing every value of the set
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> Option<Range> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+        Some(Range::new(first.start, last.end))
+    }
+
+    /// Add the values of a range, merging it with any overlapping or adjacent ranges
+    #[inline]
+    pub fn insert(&mut self, range: Range) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|other| other.end.saturating_add(1) < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end.saturating_add(1));
+
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove the values of a range, splitting any range that contains it
+    #[inline]
+    pub fn remove(&mut self, range: Range) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        if first == last {
+            return;
+        }
+
+        let mut remaining = Vec::with_capacity(2);
+        if self.ranges[first].start < range.start {
+            remaining.push(Range::new(self.ranges[first].start, range.start - 1));
+        }
+        if range.end < self.ranges[last - 1].end {
+            remaining.push(Range::new(range.end + 1, self.ranges[last - 1].end));
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    /// Check if the set contains the value in O(log n)
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(value))
+    }
+
+    /// Check if the set contains every value of the range in O(log n)
+    #[inline]
+    #[must_use]
+    pub fn contains_range(&self, range: Range) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.ranges.partition_point(|other| other.end < range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|other| other.start <= range.start && range.end <= other.end)
+    }
+
+    /// The values in either set
+    #[inline]
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.ranges.iter().copied());
+        result
+    }
+
+    /// The values in both sets
+    #[inline]
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (0, 0);
+        while let (Some(first), Some(second)) = (self.ranges.get(left), other.ranges.get(right)) {
+            let overlap = Range::new(first.start.max(second.start), first.end.min(second.end));
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Advance past the range that ends first, it cannot overlap anything else
+            if first.end < second.end {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in this set that are not in the other set
+    #[inline]
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &range in &other.ranges {
+            result.remove(range);
+        }
+        result
+    }
+
+    /// The values within the bounds that are not in the set
+    #[inline]
+    #[must_use]
+    pub fn complement(&self, bounds: Range) -> Self {
+        Self::from_iter([bounds]).difference(self)
+    }
+
+    /// The ranges of values between consecutive ranges of the set
+    #[inline]
+    pub fn gaps(&self) -> impl Iterator<Item = Range> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| Range::new(pair[0].end + 1, pair[1].start - 1))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Range> {
+        self.ranges.iter()
+    }
+}
+
+impl Extend<Range> for RangeSet {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Range>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl FromIterator<Range> for RangeSet {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> Self {
+        let mut ranges: Vec<Range> = iter.into_iter().filter(|range| !range.is_empty()).collect();
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        // Merge the sorted ranges in a single pass instead of inserting them one at a time
+        let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end.saturating_add(1) => {
+                    last.end = last.end.max(range.end);
+                }
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+}
+
+impl<'a> IntoIterator for &'a RangeSet {
+    type Item = &'a Range;
+    type IntoIter = std::slice::Iter<'a, Range>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,4 +353,125 @@ mod tests {
             assert_eq!(first.consolidate(&second), expected);
         }
     }
+
+    #[test]
+    fn test_half_open() {
+        let data: [(i64, i64, Range, i64); 3] = [
+            (3, 6, Range::new(3, 5), 3),
+            (-2, 1, Range::new(-2, 0), 3),
+            (4, 4, Range::new(4, 3), 0),
+        ];
+
+        for (start, end, expected, len) in data {
+            let range = Range::from_half_open(start, end);
+            assert_eq!(range, expected);
+            assert_eq!(range.len(), len);
+            assert_eq!(range.to_half_open(), start..end);
+            assert_eq!(Range::from(start..end), expected);
+        }
+    }
+
+    type Bounds<'a> = &'a [(i64, i64)];
+
+    fn set(ranges: Bounds) -> RangeSet {
+        ranges
+            .iter()
+            .map(|&(start, end)| Range::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let data: [(Bounds, Bounds); 5] = [
+            (&[(3, 5), (10, 14), (16, 20), (12, 18)], &[(3, 5), (10, 20)]),
+            (&[(1, 2), (3, 4), (6, 7)], &[(1, 4), (6, 7)]),
+            (&[(5, 8), (1, 2), (1, 10)], &[(1, 10)]),
+            (&[(4, 6), (5, 5), (9, 8)], &[(4, 6)]),
+            (&[(-5, -1), (1, 5), (0, 0)], &[(-5, 5)]),
+        ];
+
+        for (inserted, expected) in data {
+            let mut result = RangeSet::new();
+            for &(start, end) in inserted {
+                result.insert(Range::new(start, end));
+            }
+            assert_eq!(result, set(expected));
+            assert_eq!(set(inserted), set(expected));
+        }
+    }
+
+    #[test]
+    fn test_remove() {
+        let data: [((i64, i64), Bounds); 5] = [
+            ((4, 6), &[(1, 3), (7, 10), (15, 20)]),
+            ((1, 10), &[(15, 20)]),
+            ((8, 16), &[(1, 7), (17, 20)]),
+            ((11, 14), &[(1, 10), (15, 20)]),
+            ((0, 30), &[]),
+        ];
+
+        for ((start, end), expected) in data {
+            let mut result = set(&[(1, 10), (15, 20)]);
+            result.remove(Range::new(start, end));
+            assert_eq!(result, set(expected));
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let first = set(&[(1, 5), (10, 15), (20, 25)]);
+        let second = set(&[(4, 11), (14, 21), (30, 30)]);
+
+        assert_eq!(first.union(&second), set(&[(1, 25), (30, 30)]));
+        assert_eq!(
+            first.intersection(&second),
+            set(&[(4, 5), (10, 11), (14, 15), (20, 21)])
+        );
+        assert_eq!(
+            first.difference(&second),
+            set(&[(1, 3), (12, 13), (22, 25)])
+        );
+        assert_eq!(
+            first.complement(Range::new(0, 30)),
+            set(&[(0, 0), (6, 9), (16, 19), (26, 30)])
+        );
+        assert_eq!(first.complement(Range::new(3, 12)), set(&[(6, 9)]));
+        assert_eq!(
+            first.gaps().collect::<Vec<Range>>(),
+            [Range::new(6, 9), Range::new(16, 19)]
+        );
+        assert_eq!(first.len(), 17);
+        assert_eq!(first.bounds(), Some(Range::new(1, 25)));
+        assert_eq!(first.to_string(), "{[1, 5], [10, 15], [20, 25]}");
+    }
+
+    #[test]
+    fn test_contains() {
+        let ranges = set(&[(1, 5), (10, 15), (20, 25)]);
+        let data: [(i64, bool); 7] = [
+            (0, false),
+            (1, true),
+            (5, true),
+            (7, false),
+            (12, true),
+            (25, true),
+            (26, false),
+        ];
+
+        for (value, expected) in data {
+            assert_eq!(ranges.contains(value), expected);
+        }
+
+        let data: [(Range, bool); 5] = [
+            (Range::new(1, 5), true),
+            (Range::new(11, 14), true),
+            (Range::new(4, 10), false),
+            (Range::new(16, 19), false),
+            (Range::new(8, 7), true),
+        ];
+
+        for (range, expected) in data {
+            assert_eq!(ranges.contains_range(range), expected);
+        }
+    }
 }
@@ -0,0 +1,610 @@
+use std::any::type_name;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+use super::io::ParseError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Node {
+    Literal(String),
+    Capture,
+    Optional(Vec<Node>),
+    Repeated(Vec<Node>),
+}
+
+/// A template that extracts values from puzzle input, such as `pos=<{},{},{}>, r={}`
+///
+/// - `{}` captures a value, which cannot be empty or span more than one line
+/// - `{?...}` is a section that may be left out
+/// - `{*...}` is a section repeated any number of times, e.g. `{}{*, {}}` for a list
+/// - `{{` and `}}` match literal braces
+///
+/// Any other text must match exactly, including new lines for multi-line blocks. A value
+/// followed by text ends before the first character of that text, like `%[^,]` in scanf,
+/// otherwise it takes the shortest text that lets the rest of the template match.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    nodes: Vec<Node>,
+}
+
+impl FromStr for Pattern {
+    type Err = String;
+
+    #[inline]
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let nodes = parse_template(&mut template.chars().peekable(), template, false)?;
+        Ok(Self { nodes })
+    }
+}
+
+impl Pattern {
+    /// # Panics
+    ///
+    /// Will panic if the template is invalid, see `Pattern::from_str` to handle the error
+    #[inline]
+    #[must_use]
+    pub fn new(template: &str) -> Self {
+        template.parse().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Match the whole of the text against the pattern
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` pointing at the furthest point the text matched up to
+    #[inline]
+    pub fn captures<'a>(&self, text: &'a str) -> Result<Captures<'a>, ParseError> {
+        let mut matcher = Matcher {
+            input: text,
+            furthest: 0,
+            expected: Vec::new(),
+        };
+        let values = matcher.match_nodes(&self.nodes, 0, &mut |matcher, end| {
+            if end == text.len() {
+                Some(Vec::new())
+            } else {
+                matcher.fail(end, "end of input".to_string());
+                None
+            }
+        });
+        match values {
+            Some(mut values) => {
+                reverse_captures(&mut values);
+                Ok(Captures {
+                    input: text,
+                    values,
+                })
+            }
+            None => Err(matcher.error()),
+        }
+    }
+
+    /// Match the text and parse each value into the fields of a tuple
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the text does not match or a value cannot be parsed
+    #[inline]
+    pub fn scan<T: FromCaptures>(&self, text: &str) -> Result<T, ParseError> {
+        self.captures(text)?.parse()
+    }
+
+    /// Scan each line of the text
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` at the first line that does not match or cannot be parsed
+    #[inline]
+    pub fn scan_lines<T: FromCaptures>(&self, text: &str) -> Result<Vec<T>, ParseError> {
+        text.lines()
+            .map(|line| self.scan(line).map_err(|error| error.within(text, line)))
+            .collect()
+    }
+
+    /// Scan each block of lines of the text, with blocks separated by a blank line
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` at the first block that does not match or cannot be parsed
+    #[inline]
+    pub fn scan_blocks<T: FromCaptures>(&self, text: &str) -> Result<Vec<T>, ParseError> {
+        // Removing carriage returns keeps the line and column of every other character
+        let text = text.replace("\r\n", "\n");
+        text.split("\n\n")
+            .map(|block| self.scan(block).map_err(|error| error.within(&text, block)))
+            .collect()
+    }
+}
+
+fn parse_template(
+    chars: &mut Peekable<Chars>,
+    template: &str,
+    nested: bool,
+) -> Result<Vec<Node>, String> {
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut literal = String::new();
+    loop {
+        match chars.next() {
+            None if nested => return Err(format!("Unclosed section in pattern `{template}`")),
+            None => break,
+            Some(c @ ('{' | '}')) if chars.peek() == Some(&c) => {
+                chars.next();
+                literal.push(c);
+            }
+            Some('}') if nested => break,
+            Some('}') => return Err(format!("Unmatched `}}` in pattern `{template}`")),
+            Some('{') => {
+                if !literal.is_empty() {
+                    nodes.push(Node::Literal(literal.clone()));
+                    literal.clear();
+                }
+                let node = match chars.next() {
+                    Some('}') => Node::Capture,
+                    Some('?') => Node::Optional(parse_template(chars, template, true)?),
+                    Some('*') => Node::Repeated(parse_template(chars, template, true)?),
+                    _ => {
+                        return Err(format!(
+                            "Invalid section in pattern `{template}`, expected `{{}}`, `{{?` or `{{*`"
+                        ));
+                    }
+                };
+                if matches!(node, Node::Optional(ref inner) | Node::Repeated(ref inner) if inner.is_empty())
+                {
+                    return Err(format!("Empty section in pattern `{template}`"));
+                }
+                if node == Node::Capture && nodes.last() == Some(&Node::Capture) {
+                    return Err(format!(
+                        "Captures must be separated by text in pattern `{template}`"
+                    ));
+                }
+                nodes.push(node);
+            }
+            Some(c) => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        nodes.push(Node::Literal(literal));
+    }
+    Ok(nodes)
+}
+
+/// Number of captures a sequence of nodes adds to its `Captures`
+fn count_captures(nodes: &[Node]) -> usize {
+    nodes
+        .iter()
+        .filter(|node| !matches!(node, Node::Literal(_)))
+        .count()
+}
+
+/// Put captures collected in reverse back in the order they appear, including the captures
+/// of every section
+fn reverse_captures(values: &mut [Capture]) {
+    values.reverse();
+    for value in values {
+        match value {
+            Capture::Value(_) | Capture::Optional(None) => {}
+            Capture::Optional(Some(captures)) => reverse_captures(&mut captures.values),
+            Capture::Repeated(repetitions) => {
+                repetitions.reverse();
+                for repetition in repetitions {
+                    reverse_captures(&mut repetition.values);
+                }
+            }
+        }
+    }
+}
+
+type Continuation<'f, 'a> = dyn FnMut(&mut Matcher<'a>, usize) -> Option<Vec<Capture<'a>>> + 'f;
+
+/// Backtracking matcher that remembers the furthest point it failed at for error messages
+struct Matcher<'a> {
+    input: &'a str,
+    furthest: usize,
+    expected: Vec<String>,
+}
+
+impl<'a> Matcher<'a> {
+    fn fail(&mut self, position: usize, expected: String) {
+        if position > self.furthest {
+            self.furthest = position;
+            self.expected.clear();
+        }
+        if position == self.furthest && !self.expected.contains(&expected) {
+            self.expected.push(expected);
+        }
+    }
+
+    fn error(&self) -> ParseError {
+        let rest = &self.input[self.furthest..];
+        let text = &rest[..rest.find('\n').unwrap_or(rest.len())];
+        ParseError::at(
+            self.input,
+            text,
+            &format!("expected {}", self.expected.join(" or ")),
+        )
+    }
+
+    /// Match the nodes starting at `position`, then call `next` with the end of the match
+    ///
+    /// Returns the captures returned by `next` followed by the captures of the nodes in
+    /// reverse, so each node can push its capture once the rest has matched. The captures
+    /// of sections and repetitions are also in reverse until `reverse_captures` is called.
+    fn match_nodes(
+        &mut self,
+        nodes: &[Node],
+        position: usize,
+        next: &mut Continuation<'_, 'a>,
+    ) -> Option<Vec<Capture<'a>>> {
+        let Some((node, rest)) = nodes.split_first() else {
+            return next(self, position);
+        };
+        let input = self.input;
+        match node {
+            Node::Literal(literal) => {
+                if input[position..].starts_with(literal.as_str()) {
+                    return self.match_nodes(rest, position + literal.len(), next);
+                }
+                // Point at the first character that differs
+                let matched: usize = input[position..]
+                    .chars()
+                    .zip(literal.chars())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(c, _)| c.len_utf8())
+                    .sum();
+                self.fail(
+                    position + matched,
+                    format!("`{}`", literal[matched..].escape_debug()),
+                );
+                None
+            }
+            Node::Capture => {
+                let line = &input[position..];
+                let line = &line[..line.find('\n').unwrap_or(line.len())];
+                // A value followed by text ends before the first character of that text
+                if let Some(Node::Literal(literal)) = rest.first()
+                    && let Some(stop) = literal.chars().next()
+                {
+                    let end = position + line.find(stop).unwrap_or(line.len());
+                    if end == position {
+                        self.fail(position, "a value".to_string());
+                        return None;
+                    }
+                    let mut captures = self.match_nodes(rest, end, next)?;
+                    captures.push(Capture::Value(&input[position..end]));
+                    return Some(captures);
+                }
+
+                if line.is_empty() {
+                    self.fail(position, "a value".to_string());
+                    return None;
+                }
+                for (offset, c) in line.char_indices() {
+                    let end = position + offset + c.len_utf8();
+                    if let Some(mut captures) = self.match_nodes(rest, end, next) {
+                        captures.push(Capture::Value(&input[position..end]));
+                        return Some(captures);
+                    }
+                }
+                None
+            }
+            Node::Optional(inner) => {
+                if let Some(mut captures) =
+                    self.match_nodes(inner, position, &mut |matcher, end| {
+                        matcher.match_nodes(rest, end, next)
+                    })
+                {
+                    let values = captures.split_off(captures.len() - count_captures(inner));
+                    captures.push(Capture::Optional(Some(Captures { input, values })));
+                    return Some(captures);
+                }
+                let mut captures = self.match_nodes(rest, position, next)?;
+                captures.push(Capture::Optional(None));
+                Some(captures)
+            }
+            Node::Repeated(inner) => self.match_repeated(inner, rest, position, next),
+        }
+    }
+
+    fn match_repeated(
+        &mut self,
+        inner: &[Node],
+        rest: &[Node],
+        position: usize,
+        next: &mut Continuation<'_, 'a>,
+    ) -> Option<Vec<Capture<'a>>> {
+        // Try one more repetition first, as long as it consumes some of the input
+        if let Some(mut captures) = self.match_nodes(inner, position, &mut |matcher, end| {
+            if end == position {
+                return None;
+            }
+            matcher.match_repeated(inner, rest, end, next)
+        }) {
+            let values = captures.split_off(captures.len() - count_captures(inner));
+            if let Some(Capture::Repeated(repetitions)) = captures.last_mut() {
+                repetitions.push(Captures {
+                    input: self.input,
+                    values,
+                });
+            }
+            return Some(captures);
+        }
+        let mut captures = self.match_nodes(rest, position, next)?;
+        captures.push(Capture::Repeated(Vec::new()));
+        Some(captures)
+    }
+}
+
+/// A single capture of a pattern
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Capture<'a> {
+    /// The text captured by `{}`
+    Value(&'a str),
+    /// The captures of an optional section, if it was present
+    Optional(Option<Captures<'a>>),
+    /// The captures of each repetition of a repeated section
+    Repeated(Vec<Captures<'a>>),
+}
+
+/// The captures of a matched pattern, or of one of its sections, in the order they appear
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Captures<'a> {
+    /// The whole text that was matched, used to locate errors
+    input: &'a str,
+    values: Vec<Capture<'a>>,
+}
+
+impl<'a> Captures<'a> {
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The text of a value capture
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the capture at the index is not a value
+    #[inline]
+    #[must_use]
+    pub fn text(&self, index: usize) -> &'a str {
+        match self.values.get(index) {
+            Some(Capture::Value(text)) => text,
+            other => panic!("Capture {index} is not a value: {other:?}"),
+        }
+    }
+
+    /// Parse a value capture
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` pointing at the value if it cannot be parsed
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the capture at the index is not a value
+    #[inline]
+    pub fn get<T: FromStr>(&self, index: usize) -> Result<T, ParseError> {
+        let text = self.text(index);
+        text.parse::<T>().map_err(|_| {
+            let name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+            ParseError::at(self.input, text, &format!("expected {name}"))
+        })
+    }
+
+    /// The captures of an optional section, if it was present
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the capture at the index is not an optional section
+    #[inline]
+    #[must_use]
+    pub fn optional(&self, index: usize) -> Option<&Captures<'a>> {
+        match self.values.get(index) {
+            Some(Capture::Optional(captures)) => captures.as_ref(),
+            other => panic!("Capture {index} is not an optional section: {other:?}"),
+        }
+    }
+
+    /// The captures of each repetition of a repeated section
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the capture at the index is not a repeated section
+    #[inline]
+    #[must_use]
+    pub fn repeated(&self, index: usize) -> &[Captures<'a>] {
+        match self.values.get(index) {
+            Some(Capture::Repeated(repetitions)) => repetitions,
+            other => panic!("Capture {index} is not a repeated section: {other:?}"),
+        }
+    }
+
+    /// Parse every value into the fields of a tuple
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` pointing at the first value that cannot be parsed
+    #[inline]
+    pub fn parse<T: FromCaptures>(&self) -> Result<T, ParseError> {
+        T::from_captures(self)
+    }
+}
+
+/// Types that can be built from the values of a pattern
+pub trait FromCaptures: Sized {
+    /// # Errors
+    ///
+    /// Will return `Err` if a value cannot be parsed
+    fn from_captures(captures: &Captures) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_captures {
+    ($count:literal: $($name:ident $index:tt),+) => {
+        impl<$($name: FromStr),+> FromCaptures for ($($name,)+) {
+            /// # Panics
+            ///
+            /// Will panic if the pattern does not have exactly as many values as the tuple
+            #[inline]
+            fn from_captures(captures: &Captures) -> Result<Self, ParseError> {
+                assert_eq!(
+                    captures.len(),
+                    $count,
+                    "Pattern has {} captures but {} were requested",
+                    captures.len(),
+                    $count
+                );
+                Ok(($(captures.get::<$name>($index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1: A 0);
+impl_from_captures!(2: A 0, B 1);
+impl_from_captures!(3: A 0, B 1, C 2);
+impl_from_captures!(4: A 0, B 1, C 2, D 3);
+impl_from_captures!(5: A 0, B 1, C 2, D 3, E 4);
+impl_from_captures!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_captures!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_captures!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Bot = (i64, i64, i64, u64);
+
+    #[test]
+    fn test_scan() {
+        let pattern = Pattern::new("pos=<{},{},{}>, r={}");
+        let data: [(&str, Bot); 2] = [
+            ("pos=<0,0,0>, r=4", (0, 0, 0, 4)),
+            ("pos=<-12,130,-7>, r=1000", (-12, 130, -7, 1000)),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(pattern.scan(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let pattern = Pattern::new("pos=<{},{},{}>, r={}");
+        let data: [(&str, &str); 5] = [
+            (
+                "pos=<1,2,3>, s=4",
+                "line 1, column 14: expected `r=`, found `s=4`",
+            ),
+            (
+                "pos=<1,2>, r=4",
+                "line 1, column 15: expected `>, r=`, found nothing",
+            ),
+            (
+                "pos=<1,x,3>, r=4",
+                "line 1, column 8: expected i64, found `x`",
+            ),
+            (
+                "pos=<1,2,3>, r=",
+                "line 1, column 16: expected a value, found nothing",
+            ),
+            (
+                "pos=<1,2,3>, r=4\nmore",
+                "line 1, column 17: expected end of input, found nothing",
+            ),
+        ];
+
+        for (input, expected) in data {
+            let result = pattern.scan::<(i64, i64, i64, i64)>(input);
+            assert_eq!(
+                result.map_err(|error| error.to_string()),
+                Err(expected.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_sections() {
+        let pattern = Pattern::new("{} units{? ({}{*; {}})} at {}");
+
+        let captures = pattern
+            .captures("17 units (weak to fire; immune to cold) at 4")
+            .unwrap();
+        assert_eq!(captures.get::<i64>(0), Ok(17));
+        let modifiers = captures.optional(1).unwrap();
+        assert_eq!(modifiers.text(0), "weak to fire");
+        let rest: Vec<&str> = modifiers
+            .repeated(1)
+            .iter()
+            .map(|repetition| repetition.text(0))
+            .collect();
+        assert_eq!(rest, vec!["immune to cold"]);
+        assert_eq!(captures.get::<i64>(2), Ok(4));
+
+        let captures = pattern.captures("3 units at 1").unwrap();
+        assert_eq!(captures.optional(1), None);
+
+        let list = Pattern::new("{}{*, {}}");
+        let captures = list.captures("a, b, c").unwrap();
+        assert_eq!(captures.text(0), "a");
+        assert_eq!(captures.repeated(1).len(), 2);
+    }
+
+    #[test]
+    fn test_long_list() {
+        let values: Vec<String> = (0..200).map(|value| value.to_string()).collect();
+        let text = format!("[{}]", values.join(","));
+
+        let captures = Pattern::new("[{}{*,{}}]").captures(&text).unwrap();
+        let mut parsed: Vec<&str> = vec![captures.text(0)];
+        parsed.extend(captures.repeated(1).iter().map(|value| value.text(0)));
+        assert_eq!(parsed, values);
+    }
+
+    #[test]
+    fn test_scan_blocks() {
+        let pattern = Pattern::new("{{{}}}:\n  value {}");
+        let text = "{a}:\n  value 1\n\n{b}:\n  value 2";
+        assert_eq!(
+            pattern.scan_blocks::<(char, i64)>(text),
+            Ok(vec![('a', 1), ('b', 2)])
+        );
+
+        let text = "{a}:\r\n  value 1\r\n\r\n{b}:\r\n  value 2";
+        assert_eq!(
+            pattern.scan_blocks::<(char, i64)>(text),
+            Ok(vec![('a', 1), ('b', 2)])
+        );
+
+        let text = "{a}:\n  value 1\n\n{b}:\n  value x";
+        assert_eq!(
+            pattern
+                .scan_blocks::<(char, i64)>(text)
+                .map_err(|error| error.to_string()),
+            Err("line 5, column 9: expected i64, found `x`".to_string())
+        );
+
+        let pattern = Pattern::new("{} {}");
+        assert_eq!(
+            pattern
+                .scan_lines::<(String, u8)>("a 1\nb 256")
+                .map_err(|error| (error.line, error.column)),
+            Err((2, 3))
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let data: [&str; 5] = ["{}{}", "{?}", "{?abc", "abc}", "{x}"];
+
+        for input in data {
+            assert!(input.parse::<Pattern>().is_err());
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use super::graph::Node;
+use super::priority_queue::{PriorityQueue, PriorityQueueItem};
+
+/// The shortest path found by a search
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchResult<T: Eq + Hash> {
+    /// Total weight of the path, or the number of steps for a breadth-first search
+    pub distance: i64,
+    /// The node before each reached node on the shortest path found to it
+    pub predecessors: HashMap<T, T>,
+    /// Every node from the start to the goal, both included
+    pub path: Vec<T>,
+}
+
+impl<T: Clone + Eq + Hash> SearchResult<T> {
+    fn new(distance: i64, predecessors: HashMap<T, T>, goal: T) -> Self {
+        let mut path: Vec<T> = vec![goal];
+        while let Some(previous) = path.last().and_then(|node| predecessors.get(node)) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Self {
+            distance,
+            predecessors,
+            path,
+        }
+    }
+}
+
+/// Find the fewest steps from the start to a node matching the goal
+///
+/// `successors` lists the nodes one step away from a node.
+#[inline]
+pub fn bfs<T, G, S, I>(start: T, mut is_goal: G, mut successors: S) -> Option<SearchResult<T>>
+where
+    T: Clone + Eq + Hash,
+    G: FnMut(&T) -> bool,
+    S: FnMut(&T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut predecessors: HashMap<T, T> = HashMap::new();
+    let mut visited: HashSet<T> = HashSet::from([start.clone()]);
+    let mut queue: VecDeque<(T, i64)> = VecDeque::from([(start, 0)]);
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(SearchResult::new(steps, predecessors, node));
+        }
+        for next in successors(&node) {
+            if visited.insert(next.clone()) {
+                predecessors.insert(next.clone(), node.clone());
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Find the lowest total weight from the start to a node matching the goal
+///
+/// `successors` lists the nodes reachable from a node with the weight of each edge, which
+/// must not be negative.
+#[inline]
+pub fn dijkstra<T, G, S, I>(start: T, is_goal: G, successors: S) -> Option<SearchResult<T>>
+where
+    T: Clone + Eq + Hash,
+    G: FnMut(&T) -> bool,
+    S: FnMut(&T) -> I,
+    I: IntoIterator<Item = (T, i64)>,
+{
+    astar(start, is_goal, successors, |_| 0)
+}
+
+/// Find the lowest total weight from the start to a node matching the goal, exploring the
+/// nodes that look closest to the goal first
+///
+/// `heuristic` estimates the remaining weight from a node to the goal. The path is only
+/// guaranteed to be the shortest if the estimate never exceeds the actual weight.
+#[inline]
+pub fn astar<T, G, S, I, H>(
+    start: T,
+    mut is_goal: G,
+    mut successors: S,
+    mut heuristic: H,
+) -> Option<SearchResult<T>>
+where
+    T: Clone + Eq + Hash,
+    G: FnMut(&T) -> bool,
+    S: FnMut(&T) -> I,
+    I: IntoIterator<Item = (T, i64)>,
+    H: FnMut(&T) -> i64,
+{
+    let mut distances: HashMap<T, i64> = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<T, T> = HashMap::new();
+    let mut queue: PriorityQueue<PriorityQueueItem<(i64, T)>> = PriorityQueue::new();
+    queue.push(PriorityQueueItem::new(heuristic(&start), (0, start)));
+
+    while let Some(PriorityQueueItem {
+        data: (distance, node),
+        ..
+    }) = queue.pop()
+    {
+        // Skip nodes that were reached by a shorter path after being queued
+        if distances.get(&node).is_some_and(|&best| best < distance) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(SearchResult::new(distance, predecessors, node));
+        }
+        for (next, weight) in successors(&node) {
+            let next_distance = distance + weight;
+            if distances
+                .get(&next)
+                .is_none_or(|&best| next_distance < best)
+            {
+                distances.insert(next.clone(), next_distance);
+                predecessors.insert(next.clone(), node.clone());
+                let estimate = next_distance + heuristic(&next);
+                queue.push(PriorityQueueItem::new(estimate, (next_distance, next)));
+            }
+        }
+    }
+    None
+}
+
+/// Find the lowest total weight from the start to the goal by searching forwards from the
+/// start and backwards from the goal until the searches meet
+///
+/// `successors` lists the edges leaving a node and `predecessors` the edges entering it, as
+/// for `dijkstra`. Both are the same for an undirected graph.
+#[inline]
+pub fn bidirectional<T, S, SI, P, PI>(
+    start: T,
+    goal: T,
+    mut successors: S,
+    mut predecessors: P,
+) -> Option<SearchResult<T>>
+where
+    T: Clone + Eq + Hash,
+    S: FnMut(&T) -> SI,
+    SI: IntoIterator<Item = (T, i64)>,
+    P: FnMut(&T) -> PI,
+    PI: IntoIterator<Item = (T, i64)>,
+{
+    // Shortest distance found through the node where the searches met
+    let mut best: Option<(i64, T)> = (start == goal).then(|| (0, start.clone()));
+    let mut forward = Frontier::new(start);
+    let mut backward = Frontier::new(goal);
+
+    while let (Some(forward_distance), Some(backward_distance)) =
+        (forward.next_distance(), backward.next_distance())
+    {
+        // No path through an unexplored node can be shorter than the one already found
+        if best
+            .as_ref()
+            .is_some_and(|(distance, _)| forward_distance + backward_distance >= *distance)
+        {
+            break;
+        }
+        if forward.queue.len() <= backward.queue.len() {
+            forward.expand(&backward, &mut successors, &mut best);
+        } else {
+            backward.expand(&forward, &mut predecessors, &mut best);
+        }
+    }
+
+    let (distance, meeting) = best?;
+    let mut result = SearchResult::new(distance, forward.previous, meeting);
+    // The backward search records the node after each node on the way to the goal
+    while let Some(next) = result
+        .path
+        .last()
+        .and_then(|node| backward.previous.get(node))
+    {
+        result
+            .predecessors
+            .insert(next.clone(), result.path[result.path.len() - 1].clone());
+        result.path.push(next.clone());
+    }
+    Some(result)
+}
+
+/// One direction of a bidirectional search
+struct Frontier<T> {
+    distances: HashMap<T, i64>,
+    previous: HashMap<T, T>,
+    queue: PriorityQueue<PriorityQueueItem<(i64, T)>>,
+}
+
+impl<T: Clone + Eq + Hash> Frontier<T> {
+    fn new(start: T) -> Self {
+        let mut queue: PriorityQueue<PriorityQueueItem<(i64, T)>> = PriorityQueue::new();
+        queue.push(PriorityQueueItem::new(0, (0, start.clone())));
+        Self {
+            distances: HashMap::from([(start, 0)]),
+            previous: HashMap::new(),
+            queue,
+        }
+    }
+
+    fn next_distance(&self) -> Option<i64> {
+        self.queue.peek().map(|item| item.data.0)
+    }
+
+    /// Visit the closest queued node, recording where it meets the other search
+    fn expand<N, I>(&mut self, other: &Self, neighbours: &mut N, best: &mut Option<(i64, T)>)
+    where
+        N: FnMut(&T) -> I,
+        I: IntoIterator<Item = (T, i64)>,
+    {
+        let Some(PriorityQueueItem {
+            data: (distance, node),
+            ..
+        }) = self.queue.pop()
+        else {
+            return;
+        };
+        if self
+            .distances
+            .get(&node)
+            .is_some_and(|&best| best < distance)
+        {
+            return;
+        }
+        for (next, weight) in neighbours(&node) {
+            let next_distance = distance + weight;
+            if self
+                .distances
+                .get(&next)
+                .is_some_and(|&best| best <= next_distance)
+            {
+                continue;
+            }
+            if let Some(other_distance) = other.distances.get(&next) {
+                let total = next_distance + other_distance;
+                if best.as_ref().is_none_or(|(distance, _)| total < *distance) {
+                    *best = Some((total, next.clone()));
+                }
+            }
+            self.distances.insert(next.clone(), next_distance);
+            self.previous.insert(next.clone(), node.clone());
+            self.queue
+                .push(PriorityQueueItem::new(next_distance, (next_distance, next)));
+        }
+    }
+}
+
+/// The edges leaving a node of an explicit graph, to use as the successors of a search
+#[inline]
+pub fn edges<T: Clone + Eq + Hash>(
+    graph: &HashMap<T, Node<T>>,
+) -> impl FnMut(&T) -> Vec<(T, i64)> + '_ {
+    |value| {
+        graph.get(value).map_or_else(Vec::new, |node| {
+            node.edges
+                .iter()
+                .map(|edge| (edge.value.clone(), edge.weight))
+                .collect()
+        })
+    }
+}
+
+/// The nodes one edge away from a node of an explicit graph, ignoring the weights, to use as
+/// the successors of a breadth-first search
+#[inline]
+pub fn neighbours<T: Clone + Eq + Hash>(
+    graph: &HashMap<T, Node<T>>,
+) -> impl FnMut(&T) -> Vec<T> + '_ {
+    |value| {
+        graph.get(value).map_or_else(Vec::new, |node| {
+            node.edges.iter().map(|edge| edge.value.clone()).collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Edge, reverse};
+    use crate::grid::Grid;
+    use crate::point2d::Point2d;
+
+    /// a -1-> b -1-> c -1-> e, a -5-> d -1-> e, b -4-> d
+    fn example_graph() -> HashMap<char, Node<char>> {
+        HashMap::from([
+            ('a', Node::new(vec![Edge::new('b', 1), Edge::new('d', 5)])),
+            ('b', Node::new(vec![Edge::new('c', 1), Edge::new('d', 4)])),
+            ('c', Node::new(vec![Edge::new('e', 1)])),
+            ('d', Node::new(vec![Edge::new('e', 1)])),
+            ('e', Node::new(vec![])),
+        ])
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid<char> = "S.#\n.##\n..E".parse().unwrap();
+        let end = Point2d::new(2, 2);
+        let result = bfs(
+            Point2d::new(0, 0),
+            |point| *point == end,
+            |point| {
+                grid.neighbours(point)
+                    .filter(|(_, cell)| **cell != '#')
+                    .map(|(next, _)| next)
+                    .collect::<Vec<Point2d>>()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.distance, 4);
+        assert_eq!(result.path.len(), 5);
+        assert_eq!(result.predecessors.get(&end), Some(&Point2d::new(1, 2)));
+
+        let graph = example_graph();
+        assert_eq!(
+            bfs('a', |node| *node == 'e', neighbours(&graph)).map(|result| result.path),
+            Some(vec!['a', 'd', 'e'])
+        );
+        assert_eq!(bfs('e', |node| *node == 'a', neighbours(&graph)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = example_graph();
+        let data: [(char, char, Option<i64>, &str); 4] = [
+            ('a', 'e', Some(3), "abce"),
+            ('b', 'e', Some(2), "bce"),
+            ('d', 'd', Some(0), "d"),
+            ('e', 'a', None, ""),
+        ];
+
+        for (start, goal, distance, path) in data {
+            let expected = distance.map(|distance| (distance, path.chars().collect()));
+            let result = |result: SearchResult<char>| (result.distance, result.path);
+            assert_eq!(
+                dijkstra(start, |node| *node == goal, edges(&graph)).map(result),
+                expected
+            );
+            assert_eq!(
+                astar(start, |node| *node == goal, edges(&graph), |_| 0).map(result),
+                expected
+            );
+            assert_eq!(
+                bidirectional(start, goal, edges(&graph), edges(&reverse(&graph))).map(result),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = Point2d::new(20, 5);
+        let mut visited = 0;
+        let result = astar(
+            Point2d::new(0, 0),
+            |point| *point == goal,
+            |point| {
+                visited += 1;
+                point
+                    .neighbours()
+                    .into_iter()
+                    .filter(|next| next.x >= 0 && next.y >= 0)
+                    .map(|next| (next, 1))
+                    .collect::<Vec<(Point2d, i64)>>()
+            },
+            |point| point.manhattan(&goal),
+        )
+        .unwrap();
+
+        assert_eq!(result.distance, 25);
+        assert_eq!(result.path.first(), Some(&Point2d::new(0, 0)));
+        assert_eq!(result.path.last(), Some(&goal));
+        assert!(visited < 200);
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        let successors = |value: &i64| [(value + 1, 1), (value * 2, 1)];
+        let predecessors = |value: &i64| {
+            let mut edges: Vec<(i64, i64)> = vec![(value - 1, 1)];
+            if value % 2 == 0 {
+                edges.push((value / 2, 1));
+            }
+            edges
+        };
+
+        let result = bidirectional(1, 100, successors, predecessors).unwrap();
+        let expected = dijkstra(1, |value| *value == 100, successors).unwrap();
+        assert_eq!(result.distance, expected.distance);
+        assert_eq!(result.distance, 8);
+        assert_eq!(result.path.len(), 9);
+        for pair in result.path.windows(2) {
+            assert!(pair[1] == pair[0] + 1 || pair[1] == pair[0] * 2);
+            assert_eq!(result.predecessors.get(&pair[1]), Some(&pair[0]));
+        }
+    }
+}
@@ -1 +1,546 @@
-﻿pub type SolveFunction = fn(&str) -> (String, String);
+use std::env;
+use std::time::{Duration, Instant};
+
+use log::{debug, error, info, trace, warn};
+
+use super::examples::Params;
+use super::io::{ParseError, read_file, read_stdin};
+
+/// A puzzle solution split into a parse phase and one phase per part
+///
+/// The parsed input is shared by both parts so each phase can be run and timed on its own.
+/// Solutions that cannot detect malformed input wrap their parsed input in `Ok`.
+pub trait Solution {
+    /// The parsed representation of the puzzle input
+    type Input;
+
+    /// The title of the puzzle, if known
+    #[inline]
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Parse the raw puzzle input
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics if `try_parse` reports an error
+    #[inline]
+    fn parse(&self, file_contents: &str) -> Self::Input {
+        self.try_parse(file_contents)
+            .unwrap_or_else(|error| panic!("Invalid input at {error}"))
+    }
+
+    /// Parse the raw puzzle input, reporting malformed input to the runner instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input cannot be parsed
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parse an example input that uses different parameters to the real input
+    ///
+    /// Solutions with values that differ between the examples and the real input, such as
+    /// a number of steps, override this to read them from the parameters.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input cannot be parsed
+    #[inline]
+    fn try_parse_with(
+        &self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        let _ = params;
+        self.try_parse(file_contents)
+    }
+
+    /// Solve part 1 using the parsed input
+    fn part1(&self, input: &Self::Input) -> String;
+
+    /// Solve part 2 using the parsed input
+    fn part2(&self, input: &Self::Input) -> String;
+}
+
+/// Object safe view of a [`Solution`] used by the runners to invoke each phase
+///
+/// Implemented for every [`Solution`] so the runners can store solutions for
+/// different days, which have different input types, side by side.
+pub trait SolveModule: Sync {
+    /// The title of the puzzle, if known
+    fn title(&self) -> Option<&'static str>;
+
+    /// Parse the raw puzzle input, returning a handle that can solve each part
+    fn parse<'a>(&'a self, file_contents: &str) -> Box<dyn ParsedInput + 'a>;
+
+    /// Parse the raw puzzle input, reporting malformed input instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input cannot be parsed
+    fn try_parse<'a>(
+        &'a self,
+        file_contents: &str,
+    ) -> Result<Box<dyn ParsedInput + 'a>, ParseError>;
+
+    /// Parse an example input with the given parameters, reporting malformed input
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input cannot be parsed
+    fn try_parse_with<'a>(
+        &'a self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Box<dyn ParsedInput + 'a>, ParseError>;
+
+    /// Run every phase of the solution and time each of them
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input cannot be parsed
+    #[inline]
+    fn solve(&self, file_contents: &str) -> Result<SolveResult, ParseError> {
+        self.solve_observed(file_contents, &mut ())
+    }
+
+    /// Run every phase of the solution, timing each of them and notifying the observer
+    /// around each phase so it can take its own measurements
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input cannot be parsed, after notifying the observer that
+    /// the parse phase ended
+    #[inline]
+    fn solve_observed(
+        &self,
+        file_contents: &str,
+        observer: &mut dyn PhaseObserver,
+    ) -> Result<SolveResult, ParseError> {
+        observer.before(Phase::Parse);
+        let parse_timer = Instant::now();
+        let input = self.try_parse(file_contents);
+        let parse_time = parse_timer.elapsed();
+        observer.after(Phase::Parse);
+        let input = input?;
+
+        observer.before(Phase::Part1);
+        let part1_timer = Instant::now();
+        let part1 = input.part1();
+        let part1_time = part1_timer.elapsed();
+        observer.after(Phase::Part1);
+
+        observer.before(Phase::Part2);
+        let part2_timer = Instant::now();
+        let part2 = input.part2();
+        let part2_time = part2_timer.elapsed();
+        observer.after(Phase::Part2);
+
+        Ok(SolveResult {
+            part1,
+            part2,
+            parse_time,
+            part1_time,
+            part2_time,
+        })
+    }
+}
+
+/// A single phase of a solution
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+/// Hooks run immediately before and after each phase of a solution, outside of the timed section
+pub trait PhaseObserver {
+    #[inline]
+    fn before(&mut self, phase: Phase) {
+        let _ = phase;
+    }
+
+    #[inline]
+    fn after(&mut self, phase: Phase) {
+        let _ = phase;
+    }
+}
+
+/// Observer that takes no measurements
+impl PhaseObserver for () {}
+
+/// Parsed puzzle input bound to the solution that produced it
+pub trait ParsedInput {
+    /// Solve part 1 using the parsed input
+    fn part1(&self) -> String;
+
+    /// Solve part 2 using the parsed input
+    fn part2(&self) -> String;
+}
+
+/// The answers and phase timings of a single solve
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolveResult {
+    pub part1: String,
+    pub part2: String,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+impl SolveResult {
+    /// The combined time of every phase
+    #[inline]
+    #[must_use]
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part1_time + self.part2_time
+    }
+}
+
+/// A solution registered for a specific day of a year
+#[derive(Clone, Copy)]
+pub struct DaySolution {
+    pub day: u8,
+    pub solution: &'static dyn SolveModule,
+}
+
+/// Find the solution registered for a given day
+///
+/// # Errors
+///
+/// Will return `Err` if no solution is registered for the day
+#[inline]
+pub fn find_solve_module(
+    days: &[DaySolution],
+    day: u8,
+) -> Result<&'static dyn SolveModule, String> {
+    days.iter()
+        .find(|registered| registered.day == day)
+        .map(|registered| registered.solution)
+        .ok_or_else(|| format!("Invalid day provided: {day}"))
+}
+
+/// Run the days of a year as selected by the command line arguments
+///
+/// The first argument selects a single day to run, otherwise every day is run. The second
+/// argument is an input file to use instead of the downloaded input, `-` reads standard input.
+#[inline]
+pub fn run_year_main(year: u16, days: &[DaySolution]) {
+    let args: Vec<String> = env::args().collect();
+
+    // Specific day given
+    let selected_day: Option<u8> = args.get(1).and_then(|value| value.parse::<u8>().ok());
+    let input_path: Option<&str> = args.get(2).map(String::as_str);
+
+    if let Some(day) = selected_day {
+        run_single_day(year, days, day, input_path);
+    } else {
+        run_all_days(year, days);
+    }
+}
+
+fn run_all_days(year: u16, days: &[DaySolution]) {
+    let all_days_timer = Instant::now();
+    for registered in days {
+        run_single_day(year, days, registered.day, None);
+    }
+    info!("Total runtime: ({:?})", all_days_timer.elapsed());
+}
+
+fn run_single_day(year: u16, days: &[DaySolution], day: u8, input_path: Option<&str>) {
+    trace!("Attempting to run year {year:04} day {day:02}");
+
+    let Ok(solution) = find_solve_module(days, day) else {
+        warn!("Could not find solution for day {day:02}");
+        return;
+    };
+    let input_timer = Instant::now();
+
+    let file_path = input_path.map_or_else(
+        || format!("../inputs/{year}/day/{day}/input"),
+        str::to_string,
+    );
+    trace!("Attempting to read file at `{file_path}`");
+
+    let file_contents = if file_path == "-" {
+        read_stdin()
+    } else {
+        read_file(&file_path)
+    };
+    let Ok(file_contents) = file_contents else {
+        warn!("Could not read file at {file_path}");
+        return;
+    };
+    debug!("File read: ({:?})", input_timer.elapsed());
+
+    match solution.solve(&file_contents) {
+        Ok(result) => {
+            debug!("File parse: ({:?})", result.parse_time);
+            debug!("Part 1: {} ({:?})", result.part1, result.part1_time);
+            debug!("Part 2: {} ({:?})", result.part2, result.part2_time);
+            info!(
+                "Day {:02}: ({}, {}) ({:?})",
+                day,
+                result.part1,
+                result.part2,
+                result.total_time()
+            );
+        }
+        Err(error) => error!("Day {day:02}: invalid input at {error}"),
+    }
+}
+
+/// Parse the day number from a day module name such as `day07`
+///
+/// # Panics
+///
+/// Panics (at compile time when used in a constant) if the name is not `day` followed by a number
+#[must_use]
+pub const fn parse_day_number(module: &str) -> u8 {
+    let bytes = module.as_bytes();
+    assert!(
+        bytes.len() > 3 && bytes[0] == b'd' && bytes[1] == b'a' && bytes[2] == b'y',
+        "day modules must be named `dayNN`"
+    );
+
+    let mut day: u8 = 0;
+    let mut index = 3;
+    while index < bytes.len() {
+        assert!(
+            bytes[index].is_ascii_digit(),
+            "day modules must be named `dayNN`"
+        );
+        day = day * 10 + (bytes[index] - b'0');
+        index += 1;
+    }
+    day
+}
+
+/// Declare the day modules of a year and register their solutions
+///
+/// Each module must be named `dayNN` and expose a `SOLUTION` constant. Generates the
+/// module declarations, a `DAYS` table in the order given and a `get_solve_module`
+/// function to look up the solution for a day.
+///
+/// ```ignore
+/// aoc_helpers::register_days!(day01, day02, day03);
+/// ```
+#[macro_export]
+macro_rules! register_days {
+    ($($module:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        /// Every day with a solution
+        pub const DAYS: &[$crate::solve::DaySolution] = &[
+            $($crate::solve::DaySolution {
+                day: $crate::solve::parse_day_number(stringify!($module)),
+                solution: $module::SOLUTION,
+            },)*
+        ];
+
+        /// Get the solution registered for a given day
+        ///
+        /// # Errors
+        ///
+        /// Will return `Err` if no solution is registered for the day
+        pub fn get_solve_module(
+            day: u8,
+        ) -> Result<&'static dyn $crate::solve::SolveModule, String> {
+            $crate::solve::find_solve_module(DAYS, day)
+        }
+    };
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    #[inline]
+    fn part1(&self) -> String {
+        self.solution.part1(&self.input)
+    }
+
+    #[inline]
+    fn part2(&self) -> String {
+        self.solution.part2(&self.input)
+    }
+}
+
+impl<S: Solution + Sync> SolveModule for S {
+    #[inline]
+    fn title(&self) -> Option<&'static str> {
+        Solution::title(self)
+    }
+
+    #[inline]
+    fn parse<'a>(&'a self, file_contents: &str) -> Box<dyn ParsedInput + 'a> {
+        Box::new(Parsed {
+            solution: self,
+            input: Solution::parse(self, file_contents),
+        })
+    }
+
+    #[inline]
+    fn try_parse<'a>(
+        &'a self,
+        file_contents: &str,
+    ) -> Result<Box<dyn ParsedInput + 'a>, ParseError> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: Solution::try_parse(self, file_contents)?,
+        }))
+    }
+
+    #[inline]
+    fn try_parse_with<'a>(
+        &'a self,
+        file_contents: &str,
+        params: &Params,
+    ) -> Result<Box<dyn ParsedInput + 'a>, ParseError> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: Solution::try_parse_with(self, file_contents, params)?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::try_parse_int_list;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+            Ok(file_contents
+                .split(',')
+                .filter_map(|value| value.parse::<i64>().ok())
+                .collect())
+        }
+
+        fn part1(&self, input: &Self::Input) -> String {
+            input.iter().sum::<i64>().to_string()
+        }
+
+        fn part2(&self, input: &Self::Input) -> String {
+            input.iter().product::<i64>().to_string()
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let module: &dyn SolveModule = &Sum;
+        let input = module.parse("1,2,3,4");
+
+        assert_eq!(input.part1(), "10");
+        assert_eq!(input.part2(), "24");
+        assert_eq!(module.title(), None);
+    }
+
+    #[test]
+    fn test_try_parse() {
+        struct Strict;
+
+        impl Solution for Strict {
+            type Input = Vec<i64>;
+
+            fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+                try_parse_int_list(file_contents, ',')
+            }
+
+            fn part1(&self, input: &Self::Input) -> String {
+                input.len().to_string()
+            }
+
+            fn part2(&self, _input: &Self::Input) -> String {
+                String::new()
+            }
+        }
+
+        let module: &dyn SolveModule = &Strict;
+        assert_eq!(module.parse("1,2,3").part1(), "3");
+        assert_eq!(
+            module.solve("1,2").map(|result| result.part1),
+            Ok("2".to_string())
+        );
+        assert_eq!(
+            module
+                .solve("1,x")
+                .map_err(|error| (error.line, error.column)),
+            Err((1, 3))
+        );
+        // Solutions that skip malformed values never report errors
+        assert!((&Sum as &dyn SolveModule).solve("1,x").is_ok());
+    }
+
+    #[test]
+    fn test_parse_day_number() {
+        let data: [(&str, u8); 3] = [("day01", 1), ("day7", 7), ("day25", 25)];
+
+        for (input, expected) in data {
+            assert_eq!(parse_day_number(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_find_solve_module() {
+        let days = [DaySolution {
+            day: 3,
+            solution: &Sum,
+        }];
+
+        assert!(find_solve_module(&days, 3).is_ok());
+        assert!(find_solve_module(&days, 4).is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        let module: &dyn SolveModule = &Sum;
+        let result = module.solve("2,5").unwrap();
+
+        assert_eq!((result.part1.as_str(), result.part2.as_str()), ("7", "10"));
+        assert_eq!(
+            result.total_time(),
+            result.parse_time + result.part1_time + result.part2_time
+        );
+    }
+
+    #[test]
+    fn test_solve_observed() {
+        struct Recorder(Vec<(Phase, bool)>);
+
+        impl PhaseObserver for Recorder {
+            fn before(&mut self, phase: Phase) {
+                self.0.push((phase, false));
+            }
+
+            fn after(&mut self, phase: Phase) {
+                self.0.push((phase, true));
+            }
+        }
+
+        let module: &dyn SolveModule = &Sum;
+        let mut recorder = Recorder(Vec::new());
+        let result = module.solve_observed("2,5", &mut recorder).unwrap();
+
+        assert_eq!(result.part1, "7");
+        assert_eq!(
+            recorder.0,
+            vec![
+                (Phase::Parse, false),
+                (Phase::Parse, true),
+                (Phase::Part1, false),
+                (Phase::Part1, true),
+                (Phase::Part2, false),
+                (Phase::Part2, true),
+            ]
+        );
+    }
+}
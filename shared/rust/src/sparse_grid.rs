@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::collections::hash_map::{Iter, Values};
+use std::fmt;
+
+use super::grid::Grid;
+use super::point2d::Point2d;
+
+/// A grid without fixed bounds that stores only the cells that were set
+///
+/// The bounding box grows as cells are set, so it never needs to scan the cells. Cells that
+/// were never set read as the default value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2d, T>,
+    default: T,
+    /// Top left and bottom right corners of every cell set since the grid was last cleared
+    bounds: Option<(Point2d, Point2d)>,
+}
+
+impl<T> SparseGrid<T> {
+    #[inline]
+    #[must_use]
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Create a grid with every cell of a dense grid set
+    #[inline]
+    #[must_use]
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut sparse_grid = Self::new(default);
+        sparse_grid.extend(grid.iter().map(|(point, cell)| (point, cell.clone())));
+        sparse_grid
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Change the value read from cells that were never set
+    #[inline]
+    pub fn set_default(&mut self, default: T) {
+        self.default = default;
+    }
+
+    /// Check if the cell at the point was set
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: &Point2d) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    /// Get the cell at the point if it was set
+    #[inline]
+    #[must_use]
+    pub fn get(&self, point: &Point2d) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    /// Get the cell at the point, or the default value if it was never set
+    #[inline]
+    #[must_use]
+    pub fn get_or_default(&self, point: &Point2d) -> &T {
+        self.cells.get(point).unwrap_or(&self.default)
+    }
+
+    /// Get the cell at the point to modify it, setting it to the default value first if needed
+    #[inline]
+    pub fn get_mut(&mut self, point: Point2d) -> &mut T
+    where
+        T: Clone,
+    {
+        self.extend_bounds(point);
+        self.cells
+            .entry(point)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// Set the cell at the point, returning the previous value if it was set
+    #[inline]
+    pub fn insert(&mut self, point: Point2d, value: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Unset the cell at the point, returning its value
+    ///
+    /// The bounds are not reduced, so they still include the removed cell.
+    #[inline]
+    pub fn remove(&mut self, point: &Point2d) -> Option<T> {
+        self.cells.remove(point)
+    }
+
+    /// Unset every cell and reset the bounds
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    /// Top left and bottom right corners of the cells that were set, `None` if none were
+    #[inline]
+    #[must_use]
+    pub const fn bounds(&self) -> Option<(Point2d, Point2d)> {
+        self.bounds
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Every cell that was set, in no particular order
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, Point2d, T> {
+        self.cells.iter()
+    }
+
+    /// The value of every cell that was set, in no particular order
+    #[inline]
+    pub fn values(&self) -> Values<'_, Point2d, T> {
+        self.cells.values()
+    }
+
+    /// Every point inside the bounds, row by row
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = Point2d> + use<T> {
+        let (min, max) = self
+            .bounds
+            .unwrap_or((Point2d::new(0, 0), Point2d::new(-1, -1)));
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2d::new(x, y)))
+    }
+
+    /// Copy the cells inside the bounds into a dense grid, with the top left corner of the
+    /// bounds at `(0, 0)`
+    #[inline]
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells: Vec<T> = self
+            .points()
+            .map(|point| self.get_or_default(&point).clone())
+            .collect();
+        match Grid::from_vec(self.width(), cells) {
+            Ok(grid) => grid,
+            Err(error) => unreachable!("{error}"),
+        }
+    }
+
+    fn extend_bounds(&mut self, point: Point2d) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Point2d::new(min.x.min(point.x), min.y.min(point.y)),
+                Point2d::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+            None => (point, point),
+        });
+    }
+}
+
+impl<T> Extend<(Point2d, T)> for SparseGrid<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (Point2d, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SparseGrid<T> {
+    type Item = (&'a Point2d, &'a T);
+    type IntoIter = Iter<'a, Point2d, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    /// Render the cells inside the bounds row by row, using the default value for unset cells
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                write!(f, "{}", self.get_or_default(&Point2d::new(x, y)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid: SparseGrid<char> = SparseGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        let data: [(Point2d, (Point2d, Point2d)); 4] = [
+            (Point2d::new(0, 0), (Point2d::new(0, 0), Point2d::new(0, 0))),
+            (
+                Point2d::new(2, -1),
+                (Point2d::new(0, -1), Point2d::new(2, 0)),
+            ),
+            (
+                Point2d::new(-1, 1),
+                (Point2d::new(-1, -1), Point2d::new(2, 1)),
+            ),
+            (
+                Point2d::new(1, 0),
+                (Point2d::new(-1, -1), Point2d::new(2, 1)),
+            ),
+        ];
+
+        for (point, expected) in data {
+            grid.insert(point, '#');
+            assert_eq!(grid.bounds(), Some(expected));
+        }
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.to_string(), "...#\n.##.\n#...");
+
+        grid.remove(&Point2d::new(-1, 1));
+        assert_eq!(grid.to_string(), "...#\n.##.\n....");
+        grid.clear();
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_default() {
+        let mut grid: SparseGrid<u8> = SparseGrid::new(0);
+        *grid.get_mut(Point2d::new(3, 3)) += 2;
+        *grid.get_mut(Point2d::new(3, 3)) += 2;
+
+        assert_eq!(grid.get(&Point2d::new(3, 3)), Some(&4));
+        assert_eq!(grid.get(&Point2d::new(5, 5)), None);
+        assert_eq!(*grid.get_or_default(&Point2d::new(5, 5)), 0);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let dense: Grid<char> = "#.\n.#".parse().unwrap();
+        let mut grid = SparseGrid::from_grid(&dense, '.');
+        grid.insert(Point2d::new(-1, 2), '@');
+
+        let expected: Grid<char> = ".#.\n..#\n@..".parse().unwrap();
+        assert_eq!(grid.to_grid(), expected);
+    }
+}
@@ -0,0 +1,355 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets of the indices from 0 up to the number of elements, merged with union by
+/// size and searched with path compression
+#[derive(Clone, Debug, Default)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    /// Number of members of each set, only kept up to date for the root of the set
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Create a set for each index from 0 up to `len`
+    #[inline]
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Add a new index in a set of its own, returning the index
+    #[inline]
+    pub fn push(&mut self) -> usize {
+        self.parents.push(self.parents.len());
+        self.sizes.push(1);
+        self.components += 1;
+        self.parents.len() - 1
+    }
+
+    /// Find the index representing the set containing the index
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the index is out of bounds
+    #[inline]
+    pub fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point every index on the way directly at the root
+        let mut current = index;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merge the sets containing both indices, returning `false` if they were already merged
+    #[inline]
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // Attach the smaller set below the larger set to keep the trees shallow
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.components -= 1;
+        true
+    }
+
+    /// Check if both indices are in the same set
+    #[inline]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of members of the set containing the index
+    #[inline]
+    pub fn size(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        self.sizes[root]
+    }
+
+    /// Number of distinct sets
+    #[inline]
+    #[must_use]
+    pub const fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Number of members of each set, in the order of the lowest index of each set
+    #[inline]
+    pub fn component_sizes(&mut self) -> Vec<usize> {
+        self.components().iter().map(Vec::len).collect()
+    }
+
+    /// Members of every set, in the order of the lowest index of each set
+    #[inline]
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut positions: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for index in 0..self.len() {
+            let root = self.find(index);
+            let position = *positions.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[position].push(index);
+        }
+        components
+    }
+
+    /// Members of the set containing the index, in ascending order
+    #[inline]
+    pub fn members(&mut self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let root = self.find(index);
+        (0..self.len()).filter(move |&other| self.find(other) == root)
+    }
+}
+
+/// Disjoint sets of values, each added in a set of its own when it is first seen
+#[derive(Clone, Debug)]
+pub struct KeyedDisjointSet<T> {
+    sets: DisjointSet,
+    indices: HashMap<T, usize>,
+    values: Vec<T>,
+}
+
+impl<T: Clone + Eq + Hash> Default for KeyedDisjointSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Eq + Hash> KeyedDisjointSet<T> {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sets: DisjointSet::default(),
+            indices: HashMap::new(),
+            values: Vec::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Add a value in a set of its own unless it was already added, returning its index
+    #[inline]
+    pub fn insert(&mut self, value: T) -> usize {
+        if let Some(&index) = self.indices.get(&value) {
+            return index;
+        }
+        let index = self.sets.push();
+        self.indices.insert(value.clone(), index);
+        self.values.push(value);
+        index
+    }
+
+    /// Find the value representing the set containing the value, `None` if it was never added
+    #[inline]
+    pub fn find(&mut self, value: &T) -> Option<T> {
+        let index = *self.indices.get(value)?;
+        let root = self.sets.find(index);
+        Some(self.values[root].clone())
+    }
+
+    /// Merge the sets containing both values, adding either value if needed, returning
+    /// `false` if they were already merged
+    #[inline]
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.insert(a);
+        let b = self.insert(b);
+        self.sets.union(a, b)
+    }
+
+    /// Check if both values were added and are in the same set
+    #[inline]
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Number of members of the set containing the value, 0 if it was never added
+    #[inline]
+    pub fn size(&mut self, value: &T) -> usize {
+        self.indices
+            .get(value)
+            .map_or(0, |&index| self.sets.size(index))
+    }
+
+    /// Number of distinct sets
+    #[inline]
+    #[must_use]
+    pub const fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Number of members of each set, in the order the sets were first added
+    #[inline]
+    pub fn component_sizes(&mut self) -> Vec<usize> {
+        self.sets.component_sizes()
+    }
+
+    /// Members of every set, in the order they were added
+    #[inline]
+    pub fn components(&mut self) -> Vec<Vec<T>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| self.values[index].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Members of the set containing the value, in the order they were added
+    #[inline]
+    pub fn members(&mut self, value: &T) -> Vec<T> {
+        let Some(&index) = self.indices.get(value) else {
+            return Vec::new();
+        };
+        self.sets
+            .members(index)
+            .map(|member| self.values[member].clone())
+            .collect()
+    }
+}
+
+/// Find the edges of a minimum spanning forest using Kruskal's algorithm
+///
+/// The edges are returned in ascending order of weight. Once every node is connected, the last
+/// edge is the one that joined the final two components.
+#[inline]
+pub fn kruskal<T, W, I>(edges: I) -> Vec<(T, T, W)>
+where
+    T: Clone + Eq + Hash,
+    W: PartialOrd,
+    I: IntoIterator<Item = (T, T, W)>,
+{
+    let mut edges: Vec<(T, T, W)> = edges.into_iter().collect();
+    edges.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+
+    let mut sets: KeyedDisjointSet<T> = KeyedDisjointSet::new();
+    edges
+        .into_iter()
+        .filter(|(a, b, _)| sets.union(a.clone(), b.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut sets = DisjointSet::new(8);
+        let data: [(usize, usize, bool, usize); 6] = [
+            (0, 1, true, 7),
+            (2, 3, true, 6),
+            (1, 3, true, 5),
+            (0, 2, false, 5),
+            (5, 6, true, 4),
+            (6, 5, false, 4),
+        ];
+
+        for (a, b, merged, components) in data {
+            assert_eq!(sets.union(a, b), merged);
+            assert!(sets.connected(a, b));
+            assert_eq!(sets.component_count(), components);
+        }
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(3), 4);
+        assert_eq!(sets.component_sizes(), [4, 1, 2, 1]);
+        assert_eq!(sets.members(6).collect::<Vec<usize>>(), [5, 6]);
+        assert_eq!(sets.push(), 8);
+        assert_eq!(sets.component_count(), 5);
+    }
+
+    #[test]
+    fn test_keyed_disjoint_set() {
+        let mut sets: KeyedDisjointSet<&str> = KeyedDisjointSet::new();
+        sets.union("a", "b");
+        sets.union("c", "d");
+        sets.insert("e");
+        sets.union("b", "d");
+
+        assert_eq!(sets.len(), 5);
+        assert_eq!(sets.component_count(), 2);
+        assert!(sets.connected(&"a", &"c"));
+        assert!(!sets.connected(&"a", &"x"));
+        assert_eq!(sets.find(&"x"), None);
+        assert_eq!(sets.find(&"d"), sets.find(&"a"));
+        assert_eq!(sets.size(&"c"), 4);
+        assert_eq!(sets.members(&"e"), ["e"]);
+        assert_eq!(sets.components(), [vec!["a", "b", "c", "d"], vec!["e"]]);
+    }
+
+    #[test]
+    fn test_kruskal() {
+        let edges = [
+            ('a', 'b', 4),
+            ('a', 'c', 1),
+            ('b', 'c', 2),
+            ('b', 'd', 5),
+            ('c', 'd', 8),
+            ('d', 'e', 3),
+            ('f', 'g', 1),
+        ];
+        let tree = kruskal(edges);
+
+        assert_eq!(
+            tree,
+            [
+                ('a', 'c', 1),
+                ('f', 'g', 1),
+                ('b', 'c', 2),
+                ('d', 'e', 3),
+                ('b', 'd', 5)
+            ]
+        );
+        assert_eq!(tree.iter().map(|edge| edge.2).sum::<i64>(), 12);
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::Error;
+use std::path::Path;
+
+/// The expected answers for both parts of a single day
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The outcome of comparing a computed answer against the expected answer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    /// Parse the contents of an answers file
+    ///
+    /// Each part is stored on its own line as `part1: <answer>` and `part2: <answer>`.
+    /// Multi-line answers are stored with escaped newlines.
+    pub fn parse(file_contents: &str) -> Self {
+        let mut answers = Self::default();
+        for line in file_contents.trim_start_matches('\u{feff}').lines() {
+            if let Some((key, value)) = line.split_once(':') {
+                // Only strip the separator written by `write_answers`, answers may start with spaces
+                let value = unescape(value.strip_prefix(' ').unwrap_or(value));
+                match key.trim() {
+                    "part1" => answers.part1 = Some(value),
+                    "part2" => answers.part2 = Some(value),
+                    _ => {}
+                }
+            }
+        }
+        answers
+    }
+
+    /// Compare the computed answers against the expected answers
+    pub fn verify(&self, part1: &str, part2: &str) -> (Verdict, Verdict) {
+        (
+            compare(self.part1.as_deref(), part1),
+            compare(self.part2.as_deref(), part2),
+        )
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(part1) = &self.part1 {
+            writeln!(f, "part1: {}", escape(part1))?;
+        }
+        if let Some(part2) = &self.part2 {
+            writeln!(f, "part2: {}", escape(part2))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn get_answers_path(year: u16, day: u8) -> String {
+    format!("inputs/{year}/day/{day}/answers")
+}
+
+/// Read the expected answers for a given day
+///
+/// # Errors
+///
+/// Will return `Err` if the answers file does not exist or cannot be read
+pub fn read_answers(year: u16, day: u8) -> Result<Answers, Error> {
+    let file_contents = read_to_string(get_answers_path(year, day))?;
+    Ok(Answers::parse(&file_contents))
+}
+
+/// Write the given answers as the new expected answers for a given day
+///
+/// # Errors
+///
+/// Will return `Err` if the answers file cannot be written
+pub fn write_answers(year: u16, day: u8, answers: &Answers) -> Result<(), Error> {
+    let file_path = get_answers_path(year, day);
+    if let Some(parent) = Path::new(&file_path).parent() {
+        create_dir_all(parent)?;
+    }
+    write(file_path, answers.to_string())
+}
+
+fn compare(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(_) => Verdict::Fail,
+        None => Verdict::Unknown,
+    }
+}
+
+/// Escape a value so it fits on a single line and contains no tabs
+pub fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+pub fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('t') => result.push('\t'),
+                Some(other) => result.push(other),
+                None => result.push(c),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let data: [(&str, Answers); 3] = [
+            (
+                "part1: 1234\npart2: abc",
+                Answers {
+                    part1: Some("1234".to_string()),
+                    part2: Some("abc".to_string()),
+                },
+            ),
+            (
+                "part1: 42",
+                Answers {
+                    part1: Some("42".to_string()),
+                    part2: None,
+                },
+            ),
+            (
+                "part2: #..#\\n.##.",
+                Answers {
+                    part1: None,
+                    part2: Some("#..#\n.##.".to_string()),
+                },
+            ),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(Answers::parse(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers {
+            part1: Some("  C:\\path".to_string()),
+            part2: Some("\n#..#\n.##.\n".to_string()),
+        };
+
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers {
+            part1: Some("1234".to_string()),
+            part2: None,
+        };
+
+        assert_eq!(
+            answers.verify("1234", "5678"),
+            (Verdict::Pass, Verdict::Unknown)
+        );
+        assert_eq!(
+            answers.verify("4321", "5678"),
+            (Verdict::Fail, Verdict::Unknown)
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use std::time::Duration;
+
+use super::inputs::InputSource;
+use super::output::OutputFormat;
+
+pub const USAGE: &str = "\
+Usage:
+    AdventOfCode [--verify | --record] [--fetch] [--jobs N] [--timeout SECONDS]
+                 [--output-format text|json|csv|junit] [year] [day]
+    AdventOfCode [--input FILE | --input - | --input-dir DIR] [options] [year] [day]
+    AdventOfCode bench [--input FILE | --input - | --input-dir DIR] [year] [day] [--iterations N] [--warmup N] [--baseline NAME] [--save NAME] [--timeout SECONDS]
+    AdventOfCode examples [year] [day] [--timeout SECONDS]
+    AdventOfCode fetch [year] [day]
+    AdventOfCode history [year] [day]
+    AdventOfCode list [year]
+    AdventOfCode new <year> <day>
+    AdventOfCode report [year] [--from-history] [--jobs N] [--timeout SECONDS]
+    AdventOfCode submit <year> <day> <part> [--timeout SECONDS]
+    AdventOfCode watch <year> <day> [--input FILE] [--timeout SECONDS]";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Command {
+    /// Run the selected days once
+    Run,
+    /// Run the selected days repeatedly and report timing statistics
+    Bench,
+    /// Run the selected days against the examples from the puzzle descriptions
+    Examples,
+    /// Download the inputs of the selected days
+    Fetch,
+    /// Show how the runtime of the selected days changed between revisions
+    History,
+    /// List the solved and missing days
+    List,
+    /// Create the solution file for a day
+    New,
+    /// Write a Markdown and HTML summary of the results of the selected years
+    Report,
+    /// Submit the answer to one part of a day
+    Submit,
+    /// Run a day again every time its input, examples or source change
+    Watch,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Run the solutions and log the answers
+    Run,
+    /// Compare the answers against the recorded answers
+    Verify,
+    /// Record the answers as the new expected answers
+    Record,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    pub mode: Mode,
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    /// Download missing inputs before running
+    pub fetch: bool,
+    /// Build the report from the latest recorded runs instead of running the days
+    pub from_history: bool,
+    /// Where the inputs of the selected days are read from
+    pub input: InputSource,
+    /// Number of days to run in parallel, 0 uses every available CPU
+    pub jobs: usize,
+    /// Wall-clock time after which a day is reported as timed out
+    pub timeout: Option<Duration>,
+    /// Format the result of each day is written to standard output in
+    pub output_format: OutputFormat,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub baseline: String,
+    pub save: String,
+}
+
+impl Default for Args {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            command: Command::Run,
+            mode: Mode::Run,
+            year: None,
+            day: None,
+            part: None,
+            fetch: false,
+            from_history: false,
+            input: InputSource::Default,
+            jobs: 1,
+            timeout: None,
+            output_format: OutputFormat::Text,
+            iterations: 10,
+            warmup: 2,
+            baseline: "previous".to_string(),
+            save: "previous".to_string(),
+        }
+    }
+}
+
+/// Parse the command line arguments (excluding the program name)
+///
+/// # Errors
+///
+/// Will return `Err` if an option is unknown or an option value is missing or invalid
+pub fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut result = Args::default();
+    let mut positional: Vec<&str> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--verify" => result.mode = Mode::Verify,
+            "--record" => result.mode = Mode::Record,
+            "--fetch" => result.fetch = true,
+            "--from-history" => result.from_history = true,
+            "--input" => {
+                let file_path: String = parse_value(arg, args.next())?;
+                result.input = if file_path == "-" {
+                    InputSource::Stdin
+                } else {
+                    InputSource::File(file_path)
+                };
+            }
+            "--input-dir" => result.input = InputSource::Directory(parse_value(arg, args.next())?),
+            "--jobs" => result.jobs = parse_value(arg, args.next())?,
+            "--timeout" => {
+                let seconds: f64 = parse_value(arg, args.next())?;
+                result.timeout = Some(
+                    Duration::try_from_secs_f64(seconds)
+                        .map_err(|_| format!("Invalid value for `{arg}`: `{seconds}`"))?,
+                );
+            }
+            "--output-format" => result.output_format = parse_value(arg, args.next())?,
+            "--iterations" => result.iterations = parse_value(arg, args.next())?,
+            "--warmup" => result.warmup = parse_value(arg, args.next())?,
+            "--baseline" => result.baseline = parse_value(arg, args.next())?,
+            "--save" => result.save = parse_value(arg, args.next())?,
+            other if other.starts_with("--") => {
+                return Err(format!("Unknown option `{other}`"));
+            }
+            other => positional.push(other),
+        }
+    }
+
+    match positional.first() {
+        Some(&"bench") => result.command = Command::Bench,
+        Some(&"examples") => result.command = Command::Examples,
+        Some(&"fetch") => result.command = Command::Fetch,
+        Some(&"history") => result.command = Command::History,
+        Some(&"list") => result.command = Command::List,
+        Some(&"new") => result.command = Command::New,
+        Some(&"report") => result.command = Command::Report,
+        Some(&"submit") => result.command = Command::Submit,
+        Some(&"watch") => result.command = Command::Watch,
+        _ => {}
+    }
+    if result.command != Command::Run {
+        positional.remove(0);
+    }
+
+    if let Some(arg) = positional.first() {
+        // Specific year given
+        result.year = Some(parse_value("year", Some(*arg))?);
+    }
+    if let Some(arg) = positional.get(1) {
+        // Specific day given
+        result.day = Some(parse_value("day", Some(*arg))?);
+    }
+    if let Some(arg) = positional.get(2) {
+        // Specific part given
+        result.part = Some(parse_value("part", Some(*arg))?);
+    }
+    if result.command == Command::Submit
+        && (result.year.is_none() || result.day.is_none() || !matches!(result.part, Some(1 | 2)))
+    {
+        return Err("`submit` requires a year, a day and a part of 1 or 2".to_string());
+    }
+    if result.command == Command::New && (result.year.is_none() || result.day.is_none()) {
+        return Err("`new` requires a year and a day".to_string());
+    }
+    if result.command == Command::Report && result.day.is_some() {
+        return Err("`report` accepts a year but not a day".to_string());
+    }
+    if result.command == Command::Watch {
+        if result.year.is_none() || result.day.is_none() {
+            return Err("`watch` requires a year and a day".to_string());
+        }
+        if matches!(result.input, InputSource::Stdin | InputSource::Directory(_)) {
+            return Err(
+                "`watch` can only be used with the default input or `--input FILE`".to_string(),
+            );
+        }
+    }
+    if matches!(result.input, InputSource::File(_) | InputSource::Stdin)
+        && (result.year.is_none() || result.day.is_none())
+    {
+        return Err("`--input` requires a year and a day".to_string());
+    }
+    if result.input != InputSource::Default && result.mode != Mode::Run {
+        return Err(
+            "`--verify` and `--record` can only be used with the default inputs".to_string(),
+        );
+    }
+    if result.iterations == 0 {
+        return Err("`--iterations` must be at least 1".to_string());
+    }
+
+    Ok(result)
+}
+
+fn parse_value<T: std::str::FromStr>(
+    name: &str,
+    value: Option<impl AsRef<str>>,
+) -> Result<T, String> {
+    let Some(value) = value else {
+        return Err(format!("Missing value for `{name}`"));
+    };
+    value
+        .as_ref()
+        .parse::<T>()
+        .map_err(|_| format!("Invalid value for `{name}`: `{}`", value.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(input: &[&str]) -> Vec<String> {
+        input.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let result = parse_args(&to_args(&["--verify", "2019", "12"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                mode: Mode::Verify,
+                year: Some(2019),
+                day: Some(12),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["--jobs", "4", "--timeout", "1.5", "--fetch"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                fetch: true,
+                jobs: 4,
+                timeout: Some(Duration::from_millis(1500)),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["--output-format", "junit", "--verify"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                mode: Mode::Verify,
+                output_format: OutputFormat::Junit,
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["fetch", "2025", "1"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::Fetch,
+                year: Some(2025),
+                day: Some(1),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["--input", "-", "2019", "12"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                input: InputSource::Stdin,
+                year: Some(2019),
+                day: Some(12),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["--input-dir", "stress", "2019"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                input: InputSource::Directory("stress".to_string()),
+                year: Some(2019),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["examples", "2019", "--timeout", "2"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::Examples,
+                year: Some(2019),
+                timeout: Some(Duration::from_secs(2)),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["history", "2018", "6"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::History,
+                year: Some(2018),
+                day: Some(6),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["list", "2018"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::List,
+                year: Some(2018),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["report", "2018", "--from-history"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::Report,
+                year: Some(2018),
+                from_history: true,
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["submit", "2017", "3", "2"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::Submit,
+                year: Some(2017),
+                day: Some(3),
+                part: Some(2),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["watch", "2019", "12", "--input", "big.txt"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::Watch,
+                input: InputSource::File("big.txt".to_string()),
+                year: Some(2019),
+                day: Some(12),
+                ..Args::default()
+            })
+        );
+
+        let result = parse_args(&to_args(&["bench", "2018", "--iterations", "5"]));
+        assert_eq!(
+            result,
+            Ok(Args {
+                command: Command::Bench,
+                year: Some(2018),
+                iterations: 5,
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        let data: [&[&str]; 15] = [
+            &["--unknown"],
+            &["--output-format", "xml"],
+            &["--timeout", "-1"],
+            &["--iterations"],
+            &["--iterations", "abc"],
+            &["bench", "twenty"],
+            &["submit", "2017", "3"],
+            &["new", "2026"],
+            &["submit", "2017", "3", "3"],
+            &["--input", "big.txt", "2017"],
+            &["--input", "-"],
+            &["--verify", "--input-dir", "shared", "2018"],
+            &["report", "2019", "12"],
+            &["watch", "2019"],
+            &["watch", "2019", "12", "--input", "-"],
+        ];
+
+        for input in data {
+            assert!(parse_args(&to_args(input)).is_err());
+        }
+    }
+}
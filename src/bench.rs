@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::Error;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{trace, warn};
+
+use super::answers::{escape, unescape};
+use super::args::Args;
+use super::inputs::{DayInput, InputData, get_day_inputs};
+use super::runner::{Outcome, run_isolated};
+use super::years::{get_selected_days, get_solve_module};
+
+/// Relative change in the median below which results are considered unchanged
+const NOISE_THRESHOLD: f64 = 0.05;
+
+/// How a runtime changed relative to an earlier runtime
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    /// Within the noise threshold of the earlier runtime
+    Unchanged,
+    /// Faster by the given factor
+    Faster(f64),
+    /// Slower by the given factor
+    Slower(f64),
+}
+
+impl fmt::Display for Change {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unchanged => write!(f, "no change"),
+            Self::Faster(factor) => write!(f, "{factor:.2}x faster"),
+            Self::Slower(factor) => write!(f, "{factor:.2}x slower"),
+        }
+    }
+}
+
+/// Compare a runtime against an earlier runtime, `None` if either of them is zero
+pub fn compare_runtimes(previous: Duration, current: Duration) -> Option<Change> {
+    let previous = previous.as_secs_f64();
+    let current = current.as_secs_f64();
+    if previous == 0.0 || current == 0.0 {
+        return None;
+    }
+
+    let change = (current - previous) / previous;
+    Some(if change.abs() < NOISE_THRESHOLD {
+        Change::Unchanged
+    } else if current < previous {
+        Change::Faster(previous / current)
+    } else {
+        Change::Slower(current / previous)
+    })
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    /// Summarize a set of timing samples
+    ///
+    /// Returns `None` if no samples were given
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_owned();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the input the day ran against, `None` for the default input
+    pub label: Option<String>,
+    pub phase: String,
+    pub statistics: Statistics,
+}
+
+impl BenchResult {
+    /// Parse a result stored as `<year> <day> <phase> <min> <median> <mean> <stddev>`,
+    /// followed by the input label for inputs other than the default input
+    fn parse(line: &str) -> Option<Self> {
+        let values: Vec<&str> = line.splitn(8, ' ').collect();
+        if let [year, day, phase, min, median, mean, stddev, ref label @ ..] = values[..] {
+            let nanos = |value: &str| value.parse::<u64>().ok().map(Duration::from_nanos);
+            return Some(Self {
+                year: year.parse().ok()?,
+                day: day.parse().ok()?,
+                label: label.first().map(|label| unescape(label)),
+                phase: phase.to_string(),
+                statistics: Statistics {
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    mean: nanos(mean)?,
+                    stddev: nanos(stddev)?,
+                },
+            });
+        }
+        None
+    }
+
+    fn to_line(&self) -> String {
+        let line = format!(
+            "{} {} {} {} {} {} {}",
+            self.year,
+            self.day,
+            self.phase,
+            self.statistics.min.as_nanos(),
+            self.statistics.median.as_nanos(),
+            self.statistics.mean.as_nanos(),
+            self.statistics.stddev.as_nanos()
+        );
+        match &self.label {
+            Some(label) => format!("{line} {}", escape(label)),
+            None => line,
+        }
+    }
+}
+
+pub fn get_baseline_path(name: &str) -> String {
+    format!("benchmarks/{name}")
+}
+
+/// Read a previously saved benchmark baseline
+///
+/// # Errors
+///
+/// Will return `Err` if the baseline file does not exist or cannot be read
+pub fn read_baseline(name: &str) -> Result<Vec<BenchResult>, Error> {
+    let file_contents = read_to_string(get_baseline_path(name))?;
+    Ok(file_contents
+        .lines()
+        .filter_map(BenchResult::parse)
+        .collect())
+}
+
+/// Save benchmark results as a baseline
+///
+/// Results for days and inputs that were not benchmarked are kept from the existing baseline.
+///
+/// # Errors
+///
+/// Will return `Err` if the baseline file cannot be written
+pub fn write_baseline(name: &str, results: &[BenchResult]) -> Result<(), Error> {
+    let mut merged: Vec<BenchResult> = read_baseline(name)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|old| {
+            !results
+                .iter()
+                .any(|new| new.year == old.year && new.day == old.day && new.label == old.label)
+        })
+        .collect();
+    merged.extend(results.iter().cloned());
+    merged.sort_by(|a, b| (a.year, a.day, &a.label).cmp(&(b.year, b.day, &b.label)));
+
+    let file_path = get_baseline_path(name);
+    if let Some(parent) = Path::new(&file_path).parent() {
+        create_dir_all(parent)?;
+    }
+    let lines: Vec<String> = merged.iter().map(BenchResult::to_line).collect();
+    write(file_path, lines.join("\n") + "\n")
+}
+
+/// Benchmark the selected days, compare against the baseline and save the new results
+pub fn run_benchmarks(args: &Args) {
+    let baseline = read_baseline(&args.baseline).unwrap_or_else(|_| {
+        trace!(
+            "No baseline found at `{}`",
+            get_baseline_path(&args.baseline)
+        );
+        Vec::new()
+    });
+    // Each input of a day is compared against earlier runs of the same input
+    let baseline: HashMap<(u16, u8, Option<&str>, &str), &Statistics> = baseline
+        .iter()
+        .map(|result| {
+            (
+                (
+                    result.year,
+                    result.day,
+                    result.label.as_deref(),
+                    result.phase.as_str(),
+                ),
+                &result.statistics,
+            )
+        })
+        .collect();
+
+    println!(
+        "{:<4} {:<3} {:<6} {:>12} {:>12} {:>12} {:>12}  vs {}",
+        "Year", "Day", "Phase", "Min", "Median", "Mean", "StdDev", args.baseline
+    );
+
+    let days = get_selected_days(args.year, args.day);
+    let inputs = match get_day_inputs(&days, &args.input) {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            warn!("{error}");
+            return;
+        }
+    };
+
+    let mut results: Vec<BenchResult> = Vec::new();
+    for input in &inputs {
+        for result in benchmark_day(input, args.warmup, args.iterations, args.timeout) {
+            let (year, day) = (result.year, result.day);
+            let comparison = baseline
+                .get(&(year, day, result.label.as_deref(), result.phase.as_str()))
+                .map_or_else(
+                    || "-".to_string(),
+                    |previous| compare(previous, &result.statistics),
+                );
+            let label = result
+                .label
+                .as_ref()
+                .map(|label| format!("  ({label})"))
+                .unwrap_or_default();
+            println!(
+                "{:<4} {:<3} {:<6} {:>12.2?} {:>12.2?} {:>12.2?} {:>12.2?}  {}{}",
+                year,
+                day,
+                result.phase,
+                result.statistics.min,
+                result.statistics.median,
+                result.statistics.mean,
+                result.statistics.stddev,
+                comparison,
+                label
+            );
+            results.push(result);
+        }
+    }
+
+    if let Err(error) = write_baseline(&args.save, &results) {
+        warn!(
+            "Could not write baseline to {}: {error}",
+            get_baseline_path(&args.save)
+        );
+    }
+}
+
+/// Run every phase of a day repeatedly, each run isolated like a normal run of the day
+fn benchmark_day(
+    input: &DayInput,
+    warmup: usize,
+    iterations: usize,
+    timeout: Option<Duration>,
+) -> Vec<BenchResult> {
+    let DayInput { year, day, .. } = *input;
+    trace!("Attempting to benchmark year {year:04} day {day:02}");
+
+    let Ok(solution) = get_solve_module(year, day) else {
+        warn!("Could not find solution for year {year:04} day {day:02}");
+        return Vec::new();
+    };
+
+    let file_contents: Arc<str> = match input.read() {
+        Ok(file_contents) => file_contents.into(),
+        Err(file_path) => {
+            warn!("Could not read file at {file_path}");
+            return Vec::new();
+        }
+    };
+    let source = match &input.data {
+        InputData::File(file_path) => file_path.as_str(),
+        InputData::Text(_) => input.label.as_deref().unwrap_or("input"),
+    };
+
+    let mut parse_samples: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut part1_samples: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut part2_samples: Vec<Duration> = Vec::with_capacity(iterations);
+    for iteration in 0..warmup + iterations {
+        let job_contents = Arc::clone(&file_contents);
+        let result = run_isolated(format!("{year:04}-{day:02}"), timeout, move || {
+            solution.solve(&job_contents)
+        })
+        .and_then(|result| result.map_err(Outcome::InvalidInput));
+        let result = match result {
+            Ok(result) => result,
+            Err(Outcome::InvalidInput(error)) => {
+                warn!("Invalid input in {source} at {error}");
+                return Vec::new();
+            }
+            Err(Outcome::Panicked(message)) => {
+                warn!("Year {year:04} day {day:02} panicked: {message}");
+                return Vec::new();
+            }
+            Err(Outcome::TimedOut(timeout)) => {
+                warn!("Year {year:04} day {day:02} timed out after {timeout:?}");
+                return Vec::new();
+            }
+            Err(other) => {
+                warn!("Could not benchmark year {year:04} day {day:02}: {other:?}");
+                return Vec::new();
+            }
+        };
+        if iteration < warmup {
+            continue;
+        }
+        parse_samples.push(result.parse_time);
+        part1_samples.push(result.part1_time);
+        part2_samples.push(result.part2_time);
+    }
+
+    [
+        ("parse", parse_samples),
+        ("part1", part1_samples),
+        ("part2", part2_samples),
+    ]
+    .into_iter()
+    .filter_map(|(phase, samples)| {
+        Statistics::from_samples(&samples).map(|statistics| BenchResult {
+            year,
+            day,
+            label: input.label.clone(),
+            phase: phase.to_string(),
+            statistics,
+        })
+    })
+    .collect()
+}
+
+/// Describe the change in median runtime relative to the baseline
+fn compare(baseline: &Statistics, current: &Statistics) -> String {
+    match compare_runtimes(baseline.median, current.median) {
+        Some(change @ Change::Slower(_)) => format!("{change} (regression)"),
+        Some(change) => change.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let result = Statistics::from_samples(&samples);
+
+        assert_eq!(
+            result.map(|s| (s.min, s.median, s.mean)),
+            Some((
+                Duration::from_millis(1),
+                Duration::from_micros(2500),
+                Duration::from_micros(2500)
+            ))
+        );
+        assert_eq!(result.map(|s| s.stddev.as_micros()), Some(1118));
+        assert_eq!(Statistics::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let result = BenchResult {
+            year: 2019,
+            day: 12,
+            label: None,
+            phase: "part1".to_string(),
+            statistics: Statistics {
+                min: Duration::from_nanos(10),
+                median: Duration::from_nanos(20),
+                mean: Duration::from_nanos(30),
+                stddev: Duration::from_nanos(40),
+            },
+        };
+
+        assert_eq!(BenchResult::parse(&result.to_line()), Some(result.clone()));
+
+        let labelled = BenchResult {
+            label: Some("big input.txt".to_string()),
+            ..result
+        };
+        assert_eq!(BenchResult::parse(&labelled.to_line()), Some(labelled));
+    }
+
+    #[test]
+    fn test_compare() {
+        let statistics = |median: u64| Statistics {
+            min: Duration::ZERO,
+            median: Duration::from_millis(median),
+            mean: Duration::ZERO,
+            stddev: Duration::ZERO,
+        };
+
+        assert_eq!(compare(&statistics(100), &statistics(102)), "no change");
+        assert_eq!(compare(&statistics(100), &statistics(50)), "2.00x faster");
+        assert_eq!(
+            compare(&statistics(100), &statistics(150)),
+            "1.50x slower (regression)"
+        );
+    }
+}
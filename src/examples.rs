@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use aoc_helpers::examples::{Example, parse_examples};
+use aoc_helpers::io::read_file;
+use aoc_helpers::solve::SolveModule;
+use log::{trace, warn};
+
+use super::output::Status;
+use super::runner::{Outcome, run_isolated};
+use super::years::get_solve_module;
+
+pub fn get_examples_path(year: u16, day: u8) -> String {
+    format!("{year}/examples/day{day:02}.txt")
+}
+
+/// The outcome of running one part of an example
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExampleResult {
+    pub name: String,
+    pub part: u8,
+    pub status: Status,
+    /// The answer, or why the part failed
+    pub detail: String,
+}
+
+/// Read the examples of a day, returning `None` if the day has no examples file
+///
+/// # Errors
+///
+/// Will return `Err` if the examples file cannot be parsed
+pub fn read_examples(year: u16, day: u8) -> Option<Result<Vec<Example>, String>> {
+    let file_path = get_examples_path(year, day);
+    trace!("Attempting to read file at `{file_path}`");
+    let file_contents = read_file(&file_path).ok()?;
+    Some(parse_examples(&file_contents).map_err(|error| format!("{file_path} {error}")))
+}
+
+/// Run every example of the selected days and print whether each part gave the expected answer
+///
+/// Each part of an example runs on its own thread so a panic or a timeout only fails
+/// that part. Returns the number of failed parts, including examples files that could
+/// not be parsed.
+pub fn run_examples(days: &[(u16, u8)], timeout: Option<Duration>) -> usize {
+    let mut failures = 0;
+    for &(year, day) in days {
+        let Ok(solution) = get_solve_module(year, day) else {
+            warn!("Could not find solution for year {year:04} day {day:02}");
+            continue;
+        };
+
+        let examples = match read_examples(year, day) {
+            Some(Ok(examples)) => examples,
+            Some(Err(error)) => {
+                println!("{year:04} day {day:02}: {} {error}", Status::Fail);
+                failures += 1;
+                continue;
+            }
+            None => {
+                warn!(
+                    "Could not find examples for year {year:04} day {day:02} at {}",
+                    get_examples_path(year, day)
+                );
+                continue;
+            }
+        };
+
+        for result in check_examples(solution, &examples, timeout) {
+            if result.status.is_failure() {
+                failures += 1;
+            }
+            println!(
+                "{year:04} day {day:02} {} part {}: {:<7} {}",
+                result.name, result.part, result.status, result.detail
+            );
+        }
+    }
+    failures
+}
+
+/// Run each part of the examples that has an expected answer
+pub fn check_examples(
+    solution: &'static dyn SolveModule,
+    examples: &[Example],
+    timeout: Option<Duration>,
+) -> Vec<ExampleResult> {
+    let mut results: Vec<ExampleResult> = Vec::new();
+    for example in examples {
+        for part in [1, 2] {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+            let (status, detail) = match run_example(solution, example, part, timeout) {
+                Ok(answer) if example.matches(part, &answer) => (Status::Ok, answer),
+                Ok(answer) => (
+                    Status::Fail,
+                    format!("expected `{expected}`, got `{answer}`"),
+                ),
+                Err(Outcome::TimedOut(timeout)) => (
+                    Status::Timeout,
+                    format!("did not finish within {timeout:?}"),
+                ),
+                Err(Outcome::Panicked(message)) => (Status::Panic, message),
+                Err(Outcome::InvalidInput(error)) => {
+                    (Status::InvalidInput, format!("invalid input at {error}"))
+                }
+                Err(other) => (Status::Fail, format!("{other:?}")),
+            };
+            results.push(ExampleResult {
+                name: example.name.clone(),
+                part,
+                status,
+                detail,
+            });
+        }
+    }
+    results
+}
+
+fn run_example(
+    solution: &'static dyn SolveModule,
+    example: &Example,
+    part: u8,
+    timeout: Option<Duration>,
+) -> Result<String, Outcome> {
+    let Example { input, params, .. } = example.clone();
+    run_isolated(
+        format!("{} part {part}", example.name),
+        timeout,
+        move || {
+            let input = solution.try_parse_with(&input, &params)?;
+            Ok(if part == 1 {
+                input.part1()
+            } else {
+                input.part2()
+            })
+        },
+    )
+    .and_then(|result| result.map_err(Outcome::InvalidInput))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_helpers::io::{ParseError, try_parse_int_list};
+    use aoc_helpers::solve::Solution;
+
+    use super::*;
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = Vec<i64>;
+
+        fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+            try_parse_int_list(file_contents, ',')
+        }
+
+        fn part1(&self, input: &Self::Input) -> String {
+            input.len().to_string()
+        }
+
+        fn part2(&self, input: &Self::Input) -> String {
+            input.iter().sum::<i64>().to_string()
+        }
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let examples = parse_examples(
+            "--- example: valid
+--- input: 1,2
+--- part1: 2
+--- part2: 4
+
+--- example: invalid
+--- input: 1,x
+--- part1: 2
+",
+        )
+        .unwrap();
+
+        let results: Vec<(String, u8, Status, String)> = check_examples(&Count, &examples, None)
+            .into_iter()
+            .map(|result| (result.name, result.part, result.status, result.detail))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                ("valid".to_string(), 1, Status::Ok, "2".to_string()),
+                (
+                    "valid".to_string(),
+                    2,
+                    Status::Fail,
+                    "expected `4`, got `3`".to_string()
+                ),
+                (
+                    "invalid".to_string(),
+                    1,
+                    Status::InvalidInput,
+                    "invalid input at line 1, column 3: expected an integer, found `x`".to_string()
+                ),
+            ]
+        );
+    }
+}
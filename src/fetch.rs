@@ -0,0 +1,393 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use log::{debug, trace, warn};
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_SESSION_FILE: &str = "session";
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(3);
+
+const SESSION_ENV: &str = "AOC_SESSION";
+const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const RATE_LIMIT_ENV: &str = "AOC_RATE_LIMIT";
+
+/// Stores the time of the last request so the rate limit holds across separate runs
+pub const DEFAULT_LAST_REQUEST_PATH: &str = "inputs/.last_request";
+const USER_AGENT: &str = "github.com/jasonncleveland/adventofcode";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClientConfig {
+    /// The server inputs are downloaded from, without a trailing slash
+    pub base_url: String,
+    /// The value of the `session` cookie of a logged in user
+    pub session: String,
+    /// Minimum time between two requests to the server
+    pub rate_limit: Duration,
+    /// The file the time of the last request is recorded in
+    pub last_request_path: PathBuf,
+}
+
+impl ClientConfig {
+    /// Read the configuration from the environment
+    ///
+    /// The session token is read from `AOC_SESSION`, or otherwise from the file named by
+    /// `AOC_SESSION_FILE` (`session` by default). The server and the rate limit in seconds
+    /// can be overridden with `AOC_BASE_URL` and `AOC_RATE_LIMIT`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no session token is found or the rate limit is invalid
+    pub fn from_env() -> Result<Self, String> {
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) => session,
+            Err(_) => {
+                let file_path =
+                    env::var(SESSION_FILE_ENV).unwrap_or_else(|_| DEFAULT_SESSION_FILE.to_string());
+                trace!("Attempting to read session token from `{file_path}`");
+                fs::read_to_string(&file_path).map_err(|error| {
+                    format!(
+                        "Could not read session token from {SESSION_ENV} or {file_path}: {error}"
+                    )
+                })?
+            }
+        };
+        let session = session.trim_start_matches('\u{feff}').trim().to_string();
+        if session.is_empty() {
+            return Err("Session token is empty".to_string());
+        }
+
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let rate_limit = match env::var(RATE_LIMIT_ENV) {
+            Ok(value) => value
+                .parse::<f64>()
+                .ok()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                .ok_or_else(|| format!("Invalid value for {RATE_LIMIT_ENV}: `{value}`"))?,
+            Err(_) => DEFAULT_RATE_LIMIT,
+        };
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            rate_limit,
+            last_request_path: PathBuf::from(DEFAULT_LAST_REQUEST_PATH),
+        })
+    }
+}
+
+/// Whether an input was already on disk or had to be downloaded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FetchStatus {
+    Cached,
+    Downloaded,
+}
+
+pub fn get_input_path(year: u16, day: u8) -> String {
+    format!("inputs/{year}/day/{day}/input")
+}
+
+/// Talks to the puzzle server on behalf of a logged in user
+pub struct Client {
+    config: ClientConfig,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Self { config, agent }
+    }
+
+    /// Download the input for a day unless it has already been downloaded
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the request fails or the input cannot be written
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<FetchStatus, String> {
+        self.fetch_input_to(year, day, &get_input_path(year, day))
+    }
+
+    /// Download the input for a day to the given file unless the file already exists
+    fn fetch_input_to(&self, year: u16, day: u8, file_path: &str) -> Result<FetchStatus, String> {
+        if Path::new(file_path).exists() {
+            trace!("Input already exists at `{file_path}`");
+            return Ok(FetchStatus::Cached);
+        }
+
+        self.wait_for_rate_limit();
+        let result = self.download_input(year, day);
+        self.record_request();
+        let contents = result?;
+
+        if let Some(parent) = Path::new(file_path).parent() {
+            fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+        }
+        fs::write(file_path, contents).map_err(|error| error.to_string())?;
+        Ok(FetchStatus::Downloaded)
+    }
+
+    /// Request the input for a day from the server
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the request fails or the server does not return the input
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.config.base_url);
+        debug!("Downloading input for year {year:04} day {day:02} from {url}");
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.config.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    /// Post an answer for one part of a day and return the page the server responds with
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the request fails or the server returns an error status
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.config.base_url);
+        debug!("Submitting answer for year {year:04} day {day:02} part {part} to {url}");
+
+        self.wait_for_rate_limit();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.config.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+        self.record_request();
+        read_response(&url, response)
+    }
+
+    /// Sleep until the rate limit has passed since the last recorded request
+    fn wait_for_rate_limit(&self) {
+        let Some(last_request) = fs::read_to_string(&self.config.last_request_path)
+            .ok()
+            .and_then(|contents| contents.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+        else {
+            return;
+        };
+
+        let elapsed = SystemTime::now()
+            .duration_since(last_request)
+            .unwrap_or_default();
+        if let Some(remaining) = self.config.rate_limit.checked_sub(elapsed) {
+            debug!("Waiting {remaining:?} before the next request");
+            sleep(remaining);
+        }
+    }
+
+    fn record_request(&self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        if let Some(parent) = self.config.last_request_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Err(error) = fs::write(&self.config.last_request_path, now.to_string()) {
+            warn!("Could not record request time: {error}");
+        }
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, String> {
+    let mut response = response.map_err(|error| format!("Request to {url} failed: {error}"))?;
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|error| format!("Could not read response from {url}: {error}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Request to {url} failed with status {}: {}",
+            response.status(),
+            body.trim()
+        ));
+    }
+    Ok(body)
+}
+
+/// Download every missing input for the given days, stopping at the first failure
+///
+/// # Errors
+///
+/// Will return `Err` if the configuration cannot be read or a download fails
+pub fn fetch_inputs<F: FnMut(u16, u8, FetchStatus)>(
+    days: &[(u16, u8)],
+    mut on_fetch: F,
+) -> Result<(), String> {
+    // Only read the configuration once an input actually needs downloading
+    let mut client: Option<Client> = None;
+    for &(year, day) in days {
+        if Path::new(&get_input_path(year, day)).exists() {
+            on_fetch(year, day, FetchStatus::Cached);
+            continue;
+        }
+
+        let client = if let Some(client) = &client {
+            client
+        } else {
+            client.insert(Client::new(ClientConfig::from_env()?))
+        };
+        let status = client
+            .fetch_input(year, day)
+            .map_err(|error| format!("Could not fetch year {year:04} day {day:02}: {error}"))?;
+        on_fetch(year, day, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    /// Serve a single request and return the request that was received
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request += &line;
+            }
+            let content_length = request
+                .lines()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|value| value.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or_default();
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request += &String::from_utf8(content).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (address, handle)
+    }
+
+    fn client(base_url: String) -> Client {
+        Client::new(ClientConfig {
+            base_url,
+            session: "abc123".to_string(),
+            rate_limit: Duration::ZERO,
+            last_request_path: env::temp_dir()
+                .join(format!("aoc-fetch-test-{}", std::process::id()))
+                .join(".last_request"),
+        })
+    }
+
+    #[test]
+    fn test_download_input() {
+        let (address, handle) = serve_once("200 OK", "1\n2\n3\n");
+
+        assert_eq!(
+            client(address).download_input(2017, 4),
+            Ok("1\n2\n3\n".to_string())
+        );
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2017/day/4/input HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+        assert!(request.contains(USER_AGENT));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let directory = env::temp_dir().join(format!("aoc-fetch-input-{}", std::process::id()));
+        let file_path = directory.join("2017/day/4/input");
+        let file_path = file_path.to_string_lossy();
+
+        let (address, handle) = serve_once("200 OK", "1\n2\n3\n");
+        assert_eq!(
+            client(address).fetch_input_to(2017, 4, &file_path),
+            Ok(FetchStatus::Downloaded)
+        );
+        handle.join().unwrap();
+        assert_eq!(fs::read_to_string(&*file_path).unwrap(), "1\n2\n3\n");
+
+        // A cached input must not reach the server
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        assert_eq!(
+            client(address).fetch_input_to(2017, 4, &file_path),
+            Ok(FetchStatus::Cached)
+        );
+        listener.set_nonblocking(true).unwrap();
+        assert_eq!(
+            listener.accept().map(|_| ()).map_err(|error| error.kind()),
+            Err(ErrorKind::WouldBlock)
+        );
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (address, handle) = serve_once("200 OK", "<article>That's the right answer!</article>");
+
+        assert_eq!(
+            client(address).post_answer(2019, 1, 2, "1234"),
+            Ok("<article>That's the right answer!</article>".to_string())
+        );
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2019/day/1/answer HTTP/1.1"));
+        assert!(request.contains("application/x-www-form-urlencoded"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn test_download_input_error() {
+        let (address, handle) = serve_once("404 Not Found", "Not unlocked yet");
+
+        let result = client(address).download_input(2025, 25);
+        assert!(
+            result.is_err_and(|error| error.contains("404") && error.contains("Not unlocked yet"))
+        );
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,522 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{OpenOptions, create_dir_all, read_to_string};
+use std::io::{Error, Write};
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+use std::time::Duration;
+
+use log::{trace, warn};
+
+use super::answers::{escape, unescape};
+use super::bench::{Change, Statistics, compare_runtimes};
+use super::output::{DayRecord, Status};
+
+pub const HISTORY_PATH: &str = "history/runs";
+
+/// Critical values of Student's t-distribution for a one-sided test at 95% confidence,
+/// indexed by degrees of freedom starting from 1
+const T_CRITICAL: [f64; 30] = [
+    6.314, 2.920, 2.353, 2.132, 2.015, 1.943, 1.895, 1.860, 1.833, 1.812, 1.796, 1.782, 1.771,
+    1.761, 1.753, 1.746, 1.740, 1.734, 1.729, 1.725, 1.721, 1.717, 1.714, 1.711, 1.708, 1.706,
+    1.703, 1.701, 1.699, 1.697,
+];
+/// Critical value used once the degrees of freedom are past the end of the table
+const Z_CRITICAL: f64 = 1.645;
+
+/// A single run of a day as stored in the history
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RunEntry {
+    /// Seconds since the unix epoch when the run finished
+    pub time: u64,
+    /// The git revision the runner was built from, suffixed with `-dirty` for local changes
+    pub revision: String,
+    pub year: u16,
+    pub day: u8,
+    pub status: Status,
+    pub parse_time: Option<Duration>,
+    pub part1_time: Option<Duration>,
+    pub part2_time: Option<Duration>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl RunEntry {
+    pub fn from_record(record: &DayRecord, time: u64, revision: &str) -> Self {
+        Self {
+            time,
+            revision: revision.to_string(),
+            year: record.year,
+            day: record.day,
+            status: record.status,
+            parse_time: record.parse_time,
+            part1_time: record.part1_time,
+            part2_time: record.part2_time,
+            part1: record.part1.clone(),
+            part2: record.part2.clone(),
+        }
+    }
+
+    /// The combined time of every phase, if the run was solved
+    pub fn total_time(&self) -> Option<Duration> {
+        Some(self.parse_time? + self.part1_time? + self.part2_time?)
+    }
+}
+
+impl fmt::Display for RunEntry {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = |value: Option<Duration>| {
+            value.map_or_else(|| "-".to_string(), |v| v.as_nanos().to_string())
+        };
+        let answer = |value: &Option<String>| value.as_deref().map(escape).unwrap_or_default();
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.revision,
+            self.year,
+            self.day,
+            self.status,
+            nanos(self.parse_time),
+            nanos(self.part1_time),
+            nanos(self.part2_time),
+            answer(&self.part1),
+            answer(&self.part2)
+        )
+    }
+}
+
+impl FromStr for RunEntry {
+    type Err = String;
+
+    /// Parse a run stored as tab separated fields, with `-` for phases that did not run
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [
+            time,
+            revision,
+            year,
+            day,
+            status,
+            parse,
+            part1_time,
+            part2_time,
+            part1,
+            part2,
+        ] = fields[..]
+        else {
+            return Err(format!("Invalid run: {line}"));
+        };
+        let invalid = |_| format!("Invalid run: {line}");
+        let nanos = |value: &str| match value {
+            "-" => Ok(None),
+            value => value
+                .parse::<u64>()
+                .map(|value| Some(Duration::from_nanos(value)))
+                .map_err(invalid),
+        };
+        let answer = |value: &str| (!value.is_empty()).then(|| unescape(value));
+
+        Ok(Self {
+            time: time.parse().map_err(invalid)?,
+            revision: revision.to_string(),
+            year: year.parse().map_err(invalid)?,
+            day: day.parse().map_err(invalid)?,
+            status: status.parse()?,
+            parse_time: nanos(parse)?,
+            part1_time: nanos(part1_time)?,
+            part2_time: nanos(part2_time)?,
+            part1: answer(part1),
+            part2: answer(part2),
+        })
+    }
+}
+
+/// The runs of a day made from a single revision
+#[derive(Clone, Debug, PartialEq)]
+struct RevisionSummary<'a> {
+    revision: &'a str,
+    /// Time of the first run from the revision
+    time: u64,
+    runs: Vec<&'a RunEntry>,
+}
+
+impl RevisionSummary<'_> {
+    fn samples<F: Fn(&RunEntry) -> Option<Duration>>(&self, phase: F) -> Vec<Duration> {
+        self.runs.iter().filter_map(|run| phase(run)).collect()
+    }
+
+    fn median<F: Fn(&RunEntry) -> Option<Duration>>(&self, phase: F) -> Option<Duration> {
+        Statistics::from_samples(&self.samples(phase)).map(|statistics| statistics.median)
+    }
+
+    /// The answers of the latest solved run
+    fn answers(&self) -> Option<(Option<&str>, Option<&str>)> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|run| {
+                !matches!(
+                    run.status,
+                    Status::Panic | Status::Timeout | Status::InvalidInput
+                )
+            })
+            .map(|run| (run.part1.as_deref(), run.part2.as_deref()))
+    }
+}
+
+/// Returns the current git revision, or `unknown` if git is not available
+pub fn get_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(revision) = git(&["rev-parse", "--short=12", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{revision}-dirty"),
+        _ => revision,
+    }
+}
+
+/// Read every run recorded in the history
+///
+/// # Errors
+///
+/// Will return `Err` if the history file does not exist or cannot be read
+pub fn read_history() -> Result<Vec<RunEntry>, Error> {
+    let file_contents = read_to_string(HISTORY_PATH)?;
+    Ok(file_contents
+        .lines()
+        .filter_map(|line| match line.parse::<RunEntry>() {
+            Ok(entry) => Some(entry),
+            Err(error) => {
+                warn!("{error}");
+                None
+            }
+        })
+        .collect())
+}
+
+/// Append runs to the history
+///
+/// # Errors
+///
+/// Will return `Err` if the history file cannot be written
+pub fn append_history(entries: &[RunEntry]) -> Result<(), Error> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    if let Some(parent) = Path::new(HISTORY_PATH).parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_PATH)?;
+    for entry in entries {
+        writeln!(file, "{entry}")?;
+    }
+    Ok(())
+}
+
+/// Print a trend table for each selected day with recorded runs
+///
+/// Returns the number of days whose latest revision is significantly slower than the
+/// revision before it.
+pub fn show_history(year: Option<u16>, day: Option<u8>) -> usize {
+    let entries = read_history().unwrap_or_else(|_| {
+        trace!("No history recorded at `{HISTORY_PATH}`");
+        Vec::new()
+    });
+
+    let mut days: BTreeMap<(u16, u8), Vec<&RunEntry>> = BTreeMap::new();
+    for entry in &entries {
+        if year.is_none_or(|year| year == entry.year) && day.is_none_or(|day| day == entry.day) {
+            days.entry((entry.year, entry.day)).or_default().push(entry);
+        }
+    }
+    if days.is_empty() {
+        println!("No runs recorded in {HISTORY_PATH}");
+        return 0;
+    }
+
+    let mut slowdowns = 0;
+    for ((year, day), runs) in days {
+        println!("{year:04} day {day:02}");
+        println!(
+            "  {:<18} {:<10} {:>4} {:>12} {:>12} {:>12} {:>12}  Change",
+            "Revision", "Date", "Runs", "Parse", "Part 1", "Part 2", "Total"
+        );
+
+        let summaries = summarize(&runs);
+        let mut latest_slower = false;
+        for (index, summary) in summaries.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|index| &summaries[index]);
+            let (change, slower) = previous.map_or_else(
+                || ("-".to_string(), false),
+                |previous| compare_revisions(previous, summary),
+            );
+            latest_slower = slower;
+
+            let median = |phase: fn(&RunEntry) -> Option<Duration>| {
+                summary
+                    .median(phase)
+                    .map_or_else(|| "-".to_string(), |median| format!("{median:.2?}"))
+            };
+            println!(
+                "  {:<18} {:<10} {:>4} {:>12} {:>12} {:>12} {:>12}  {}",
+                summary.revision,
+                format_date(summary.time),
+                summary.runs.len(),
+                median(|run| run.parse_time),
+                median(|run| run.part1_time),
+                median(|run| run.part2_time),
+                median(RunEntry::total_time),
+                change
+            );
+        }
+        if latest_slower {
+            slowdowns += 1;
+        }
+    }
+
+    if slowdowns > 0 {
+        println!("{slowdowns} day(s) significantly slower at the latest revision");
+    }
+    slowdowns
+}
+
+/// Group the runs of a day by revision, in the order each revision was first run
+fn summarize<'a>(runs: &[&'a RunEntry]) -> Vec<RevisionSummary<'a>> {
+    let mut summaries: Vec<RevisionSummary<'a>> = Vec::new();
+    for &run in runs {
+        match summaries
+            .iter_mut()
+            .find(|summary| summary.revision == run.revision)
+        {
+            Some(summary) => summary.runs.push(run),
+            None => summaries.push(RevisionSummary {
+                revision: &run.revision,
+                time: run.time,
+                runs: vec![run],
+            }),
+        }
+    }
+    summaries
+}
+
+/// Describe the change in total runtime between two revisions and whether it is a
+/// statistically significant slowdown
+fn compare_revisions(previous: &RevisionSummary, current: &RevisionSummary) -> (String, bool) {
+    let mut notes: Vec<String> = Vec::new();
+    if let (Some(previous), Some(current)) = (previous.answers(), current.answers())
+        && previous != current
+    {
+        notes.push("answers changed".to_string());
+    }
+
+    let seconds = |summary: &RevisionSummary| -> Vec<f64> {
+        summary
+            .samples(RunEntry::total_time)
+            .iter()
+            .map(Duration::as_secs_f64)
+            .collect()
+    };
+    let (before, after) = (seconds(previous), seconds(current));
+    let mut slower = false;
+    if let (Some(before_median), Some(after_median)) = (
+        previous.median(RunEntry::total_time),
+        current.median(RunEntry::total_time),
+    ) && let Some(change) = compare_runtimes(before_median, after_median)
+    {
+        let note = if let Change::Slower(_) = change {
+            let significance = match is_significantly_slower(&before, &after) {
+                Some(true) => {
+                    slower = true;
+                    "significant"
+                }
+                Some(false) => "not significant",
+                None => "too few runs",
+            };
+            format!("{change} ({significance})")
+        } else {
+            change.to_string()
+        };
+        notes.insert(0, note);
+    }
+
+    if notes.is_empty() {
+        return ("-".to_string(), false);
+    }
+    (notes.join(", "), slower)
+}
+
+/// One-sided Welch's t-test at 95% confidence for whether the `after` samples are slower
+/// than the `before` samples
+///
+/// Returns `None` if either side has fewer than two samples.
+fn is_significantly_slower(before: &[f64], after: &[f64]) -> Option<bool> {
+    if before.len() < 2 || after.len() < 2 {
+        return None;
+    }
+
+    let moments = |samples: &[f64]| {
+        let count = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / (count - 1.0);
+        (count, mean, variance / count)
+    };
+    let (before_count, before_mean, before_error) = moments(before);
+    let (after_count, after_mean, after_error) = moments(after);
+
+    let error = before_error + after_error;
+    if error == 0.0 {
+        return Some(after_mean > before_mean);
+    }
+    let t = (after_mean - before_mean) / error.sqrt();
+    let degrees_of_freedom = error.powi(2)
+        / (before_error.powi(2) / (before_count - 1.0) + after_error.powi(2) / (after_count - 1.0));
+
+    let critical = T_CRITICAL
+        .get((degrees_of_freedom.floor() as usize).max(1) - 1)
+        .copied()
+        .unwrap_or(Z_CRITICAL);
+    Some(t > critical)
+}
+
+/// Format seconds since the unix epoch as a `YYYY-MM-DD` date in UTC
+fn format_date(time: u64) -> String {
+    // Days to civil date conversion from Howard Hinnant's date algorithms
+    let days = (time / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(revision: &str, total_ms: u64) -> RunEntry {
+        RunEntry {
+            time: 1_700_000_000,
+            revision: revision.to_string(),
+            year: 2019,
+            day: 12,
+            status: Status::Ok,
+            parse_time: Some(Duration::ZERO),
+            part1_time: Some(Duration::from_millis(total_ms)),
+            part2_time: Some(Duration::ZERO),
+            part1: Some("179".to_string()),
+            part2: Some("2772".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let entries = [
+            run("e61a99c-dirty", 5),
+            RunEntry {
+                part2: Some("#.\t\n.#".to_string()),
+                ..run("e61a99c", 5)
+            },
+            RunEntry {
+                status: Status::Timeout,
+                parse_time: None,
+                part1_time: None,
+                part2_time: None,
+                part1: None,
+                part2: None,
+                ..run("e61a99c", 0)
+            },
+        ];
+
+        for entry in entries {
+            assert_eq!(entry.to_string().parse::<RunEntry>(), Ok(entry));
+        }
+        assert!("1700000000\tabc\t2019".parse::<RunEntry>().is_err());
+    }
+
+    #[test]
+    fn test_is_significantly_slower() {
+        let data: [(&[f64], &[f64], Option<bool>); 5] = [
+            (&[1.0, 1.1, 0.9, 1.0], &[3.0, 3.1, 2.9, 3.0], Some(true)),
+            // Significant one-sided but not two-sided
+            (&[1.0, 1.2, 1.0, 1.2], &[1.18, 1.38, 1.18, 1.38], Some(true)),
+            (&[1.0, 3.0, 1.0, 3.0], &[1.2, 3.2, 1.2, 3.2], Some(false)),
+            (&[3.0, 3.1, 2.9], &[1.0, 1.1, 0.9], Some(false)),
+            (&[1.0], &[3.0, 3.1], None),
+        ];
+
+        for (before, after, expected) in data {
+            assert_eq!(is_significantly_slower(before, after), expected);
+        }
+    }
+
+    #[test]
+    fn test_compare_revisions() {
+        let before = [run("a", 10), run("a", 11), run("a", 10)];
+        let after = [run("b", 30), run("b", 31), run("b", 30)];
+        let same = [run("c", 10), run("c", 10)];
+        let runs: Vec<&RunEntry> = before.iter().chain(&after).chain(&same).collect();
+        let summaries = summarize(&runs);
+
+        assert_eq!(summaries.len(), 3);
+        assert_eq!(
+            compare_revisions(&summaries[0], &summaries[1]),
+            ("3.00x slower (significant)".to_string(), true)
+        );
+        assert_eq!(
+            compare_revisions(&summaries[1], &summaries[2]),
+            ("3.00x faster".to_string(), false)
+        );
+
+        let changed = [RunEntry {
+            part1: Some("180".to_string()),
+            ..run("d", 10)
+        }];
+        let runs: Vec<&RunEntry> = same.iter().chain(&changed).collect();
+        let summaries = summarize(&runs);
+        assert_eq!(
+            compare_revisions(&summaries[0], &summaries[1]),
+            ("no change, answers changed".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn test_format_date() {
+        let data: [(u64, &str); 3] = [
+            (0, "1970-01-01"),
+            (951_782_400, "2000-02-29"),
+            (1_792_281_600, "2026-10-18"),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(format_date(input), expected);
+        }
+    }
+}
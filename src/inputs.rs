@@ -0,0 +1,192 @@
+use std::fs::read_dir;
+use std::sync::Arc;
+
+use aoc_helpers::io::{read_file, read_stdin};
+use log::warn;
+
+use super::fetch::get_input_path;
+
+/// Where the puzzle inputs of the selected days are read from
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum InputSource {
+    /// The downloaded input at `inputs/{year}/day/{day}/input`
+    #[default]
+    Default,
+    /// A single input file for the selected day
+    File(String),
+    /// Standard input, read once before the selected day runs
+    Stdin,
+    /// Every file in `{directory}/{year}/day/{day}`, each run as a separate input
+    Directory(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputData {
+    /// Read from the file at the given path when the day runs
+    File(String),
+    /// Input that has already been read
+    Text(Arc<str>),
+}
+
+/// A single input to run a day against
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DayInput {
+    pub year: u16,
+    pub day: u8,
+    /// Name shown alongside the results, `None` for the default input
+    pub label: Option<String>,
+    pub data: InputData,
+}
+
+impl DayInput {
+    /// The downloaded input of a day
+    pub fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            label: None,
+            data: InputData::File(get_input_path(year, day)),
+        }
+    }
+
+    /// Read the contents of the input
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` with the path of the input if the file cannot be read
+    pub fn read(&self) -> Result<String, String> {
+        match &self.data {
+            InputData::File(file_path) => read_file(file_path).map_err(|_| file_path.clone()),
+            InputData::Text(text) => Ok(text.to_string()),
+        }
+    }
+}
+
+/// Returns every input to run for the selected days
+///
+/// # Errors
+///
+/// Will return `Err` if standard input cannot be read
+pub fn get_day_inputs(days: &[(u16, u8)], source: &InputSource) -> Result<Vec<DayInput>, String> {
+    let inputs = match source {
+        InputSource::Default => days
+            .iter()
+            .map(|&(year, day)| DayInput::new(year, day))
+            .collect(),
+        InputSource::File(file_path) => days
+            .iter()
+            .map(|&(year, day)| DayInput {
+                year,
+                day,
+                label: Some(file_path.clone()),
+                data: InputData::File(file_path.clone()),
+            })
+            .collect(),
+        InputSource::Stdin => {
+            let text: Arc<str> = read_stdin()
+                .map_err(|error| format!("Could not read standard input: {error}"))?
+                .into();
+            days.iter()
+                .map(|&(year, day)| DayInput {
+                    year,
+                    day,
+                    label: Some("stdin".to_string()),
+                    data: InputData::Text(Arc::clone(&text)),
+                })
+                .collect()
+        }
+        InputSource::Directory(directory) => days
+            .iter()
+            .flat_map(|&(year, day)| get_directory_inputs(directory, year, day))
+            .collect(),
+    };
+    Ok(inputs)
+}
+
+/// Returns one input per file in the directory of a day, ordered by file name
+///
+/// A day without a readable directory gets a single input pointing at the directory so
+/// it is reported as missing its input.
+fn get_directory_inputs(directory: &str, year: u16, day: u8) -> Vec<DayInput> {
+    let day_directory = format!("{directory}/{year}/day/{day}");
+    let mut file_names: Vec<String> = match read_dir(&day_directory) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_file()))
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|file_name| !file_name.starts_with('.'))
+            .collect(),
+        Err(error) => {
+            warn!("Could not read directory {day_directory}: {error}");
+            Vec::new()
+        }
+    };
+    file_names.sort_unstable();
+
+    if file_names.is_empty() {
+        return vec![DayInput {
+            year,
+            day,
+            label: Some(day_directory.clone()),
+            data: InputData::File(day_directory),
+        }];
+    }
+    file_names
+        .into_iter()
+        .map(|file_name| DayInput {
+            year,
+            day,
+            data: InputData::File(format!("{day_directory}/{file_name}")),
+            label: Some(file_name),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    use super::*;
+
+    #[test]
+    fn test_get_day_inputs() {
+        let days = [(2019, 1), (2019, 2)];
+
+        let inputs = get_day_inputs(&days, &InputSource::Default).unwrap();
+        assert_eq!(inputs, vec![DayInput::new(2019, 1), DayInput::new(2019, 2)]);
+
+        let inputs = get_day_inputs(&days[..1], &InputSource::File("big.txt".to_string()));
+        assert_eq!(
+            inputs,
+            Ok(vec![DayInput {
+                year: 2019,
+                day: 1,
+                label: Some("big.txt".to_string()),
+                data: InputData::File("big.txt".to_string()),
+            }])
+        );
+    }
+
+    #[test]
+    fn test_get_directory_inputs() {
+        let directory = temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let day_directory = directory.join("2018/day/3");
+        create_dir_all(&day_directory).unwrap();
+        for file_name in ["stress", "alice", ".hidden"] {
+            write(day_directory.join(file_name), "#1 @ 1,3: 4x4\n").unwrap();
+        }
+        let directory = directory.to_string_lossy().to_string();
+
+        let inputs = get_directory_inputs(&directory, 2018, 3);
+        let labels: Vec<Option<&str>> = inputs.iter().map(|input| input.label.as_deref()).collect();
+        assert_eq!(labels, vec![Some("alice"), Some("stress")]);
+        assert_eq!(inputs[0].read(), Ok("#1 @ 1,3: 4x4".to_string()));
+
+        let missing = get_directory_inputs(&directory, 2018, 4);
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].read().is_err());
+
+        remove_dir_all(&directory).unwrap();
+    }
+}
@@ -1,95 +1,228 @@
+mod answers;
+mod args;
+mod bench;
+mod examples;
+mod fetch;
+mod history;
+mod inputs;
+mod memory;
+mod output;
+mod report;
+mod results;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
 mod years;
 
+use std::collections::BTreeMap;
 use std::env;
-use std::time::Instant;
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use aoc_helpers::io::read_file;
-use log::{debug, info, trace, warn};
+use log::{info, warn};
 
-use years::{YEARS, get_max_days, get_solve_module};
+use args::{Command, USAGE, parse_args};
+use bench::run_benchmarks;
+use examples::run_examples;
+use fetch::{FetchStatus, fetch_inputs, get_input_path};
+use history::{HISTORY_PATH, RunEntry, append_history, get_revision, show_history};
+use inputs::get_day_inputs;
+use output::{ResultWriter, Status};
+use report::create_report;
+use results::handle_report;
+use runner::run_days;
+use scaffold::create_day;
+use submit::{Response, submit_answer};
+use watch::watch_day;
+use years::{get_selected_days, get_solve_module, list_days};
 
-fn main() {
+fn main() -> ExitCode {
     env_logger::init();
 
-    let args: Vec<String> = env::args().collect();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.command == Command::Bench {
+        run_benchmarks(&args);
+        return ExitCode::SUCCESS;
+    }
 
-    let mut selected_year: Option<u16> = None;
-    let mut selected_day: Option<u8> = None;
-    if args.len() >= 2
-        && let Some(arg) = args.get(1)
+    if args.command == Command::List {
+        list_days(args.year);
+        return ExitCode::SUCCESS;
+    }
+    if args.command == Command::History {
+        show_history(args.year, args.day);
+        return ExitCode::SUCCESS;
+    }
+    if args.command == Command::New
+        && let (Some(year), Some(day)) = (args.year, args.day)
     {
-        // Specific year given
-        if let Ok(value) = arg.parse::<u16>() {
-            selected_year = Some(value);
-        }
+        return match create_day(year, day) {
+            Ok(changed) => {
+                for file_path in changed {
+                    println!("{file_path}");
+                }
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
     }
-    if args.len() >= 3
-        && let Some(arg) = args.get(2)
+    if args.command == Command::Submit
+        && let (Some(year), Some(day), Some(part)) = (args.year, args.day, args.part)
     {
-        // Specific day given
-        if let Ok(value) = arg.parse::<u8>() {
-            selected_day = Some(value);
+        return match submit_answer(year, day, part, args.timeout) {
+            Ok(Response::Correct) => ExitCode::SUCCESS,
+            Ok(_) => ExitCode::FAILURE,
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.command == Command::Report {
+        return match create_report(args.year, args.from_history, args.jobs, args.timeout) {
+            Ok(written) => {
+                for file_path in written {
+                    println!("{file_path}");
+                }
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+    if args.command == Command::Watch
+        && let (Some(year), Some(day)) = (args.year, args.day)
+    {
+        return match watch_day(year, day, &args.input, args.timeout) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let days = get_selected_days(args.year, args.day);
+    if args.command == Command::Fetch {
+        return fetch_selected_days(&days);
+    }
+    if args.command == Command::Examples {
+        if run_examples(&days, args.timeout) > 0 {
+            return ExitCode::FAILURE;
         }
+        return ExitCode::SUCCESS;
+    }
+    if args.fetch {
+        fetch_missing_inputs(&days);
     }
 
-    if let Some(year) = selected_year {
-        if let Some(day) = selected_day {
-            run_single_day(year, day);
-        } else {
-            run_all_days(year);
+    let inputs = match get_day_inputs(&days, &args.input) {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let all_days_timer = Instant::now();
+    let mut writer = ResultWriter::new(args.output_format);
+    let mut year_runtimes: BTreeMap<u16, Duration> = BTreeMap::new();
+    let mut cpu_time: Option<Duration> = Some(Duration::ZERO);
+    let mut history: Vec<RunEntry> = Vec::new();
+    let revision = get_revision();
+    run_days(&inputs, args.jobs, args.timeout, |report| {
+        *year_runtimes.entry(report.year).or_default() += report.elapsed;
+        cpu_time = cpu_time
+            .zip(report.cpu_time)
+            .map(|(total, day)| total + day);
+        let record = handle_report(report, args.mode, writer.format());
+        // Only sequential runs against the default inputs are comparable between revisions,
+        // days running in parallel compete for the CPU
+        if args.jobs == 1
+            && record.input.is_none()
+            && !matches!(
+                record.status,
+                Status::MissingInput | Status::MissingSolution
+            )
+        {
+            history.push(RunEntry::from_record(&record, get_timestamp(), &revision));
         }
-    } else {
-        run_all_years();
+        writer.write(record);
+    });
+    if let Err(error) = append_history(&history) {
+        warn!("Could not write history to {HISTORY_PATH}: {error}");
     }
-}
 
-fn run_all_years() {
-    trace!("Attempting to run all years");
-    let all_years_timer = Instant::now();
-    for year in YEARS {
-        run_all_days(year);
+    for (year, runtime) in &year_runtimes {
+        info!("Year {year:04} runtime: ({runtime:?})");
+    }
+    info!(
+        "Cumulative runtime: ({:?})",
+        year_runtimes.values().sum::<Duration>()
+    );
+    match cpu_time {
+        Some(cpu_time) => info!("Cumulative CPU time: ({cpu_time:?})"),
+        None => info!("Cumulative CPU time: (not measured for every day)"),
     }
-    info!("Total runtime: ({:?})", all_years_timer.elapsed());
+    info!("Total runtime: ({:?})", all_days_timer.elapsed());
+
+    if writer.finish() > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
 }
 
-fn run_all_days(year: u16) {
-    trace!("Attempting to run all days for year {year:04}");
-    if let Some(max_days) = get_max_days(year) {
-        let all_days_timer = Instant::now();
-        for day in 1..=max_days {
-            run_single_day(year, day);
-        }
-        info!("Total runtime: ({:?})", all_days_timer.elapsed());
-    } else {
-        warn!("Could not find max number of days for year {year:04}");
+/// Seconds since the unix epoch
+fn get_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn fetch_selected_days(days: &[(u16, u8)]) -> ExitCode {
+    let result = fetch_inputs(days, |year, day, status| match status {
+        FetchStatus::Cached => println!("{year:04} day {day:02}: input already exists"),
+        FetchStatus::Downloaded => println!(
+            "{year:04} day {day:02}: downloaded input to {}",
+            get_input_path(year, day)
+        ),
+    });
+    if let Err(error) = result {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
     }
+    ExitCode::SUCCESS
 }
 
-fn run_single_day(year: u16, day: u8) {
-    trace!("Attempting to run year {year:04} day {day:02}");
-
-    if let Ok(solve) = get_solve_module(year, day) {
-        let input_timer = Instant::now();
-
-        let file_path = format!("inputs/{year}/day/{day}/input");
-        trace!("Attempting to read file at `{file_path}`");
-
-        if let Ok(file_contents) = read_file(&file_path) {
-            debug!("File read: ({:?})", input_timer.elapsed());
-
-            let solve_timer = Instant::now();
-            let (part1, part2) = solve(&file_contents);
-            info!(
-                "Day {:02}: ({}, {}) ({:?})",
-                day,
-                part1,
-                part2,
-                solve_timer.elapsed()
-            );
-        } else {
-            warn!("Could not read file at {file_path}");
+/// Downloads the missing inputs of the days that have a solution
+fn fetch_missing_inputs(days: &[(u16, u8)]) {
+    let solved_days: Vec<(u16, u8)> = days
+        .iter()
+        .copied()
+        .filter(|&(year, day)| get_solve_module(year, day).is_ok())
+        .collect();
+    let result = fetch_inputs(&solved_days, |year, day, status| {
+        if status == FetchStatus::Downloaded {
+            info!("Downloaded input for year {year:04} day {day:02}");
         }
-    } else {
-        warn!("Could not find solution for year {year:04} day {day:02}");
+    });
+    if let Err(error) = result {
+        warn!("{error}");
     }
 }
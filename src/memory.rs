@@ -0,0 +1,256 @@
+use std::cell::Cell;
+use std::fmt;
+
+use aoc_helpers::solve::{Phase, PhaseObserver};
+
+/// Whether the runner was built with the counting allocator (`--features memory`)
+pub const ENABLED: bool = cfg!(feature = "memory");
+
+/// Allocations made on the current thread since it started
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    /// Bytes currently allocated, can go negative when memory allocated on another thread is freed
+    live: i64,
+    /// Highest value of `live` since the peak was last reset
+    peak: i64,
+}
+
+thread_local! {
+    // Const initialized without a destructor so the allocator can use it without allocating
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn get_counters() -> Counters {
+    COUNTERS.try_with(Cell::get).unwrap_or_default()
+}
+
+fn update_counters<F: FnOnce(&mut Counters)>(update: F) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut value = counters.get();
+        update(&mut value);
+        counters.set(value);
+    });
+}
+
+/// Memory used by a single phase of a solution
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryStats {
+    /// Number of allocations and reallocations
+    pub allocations: u64,
+    /// Total bytes requested by those allocations
+    pub bytes: u64,
+    /// Most bytes live at once during the phase, counted from the start of the parse phase
+    pub peak_bytes: u64,
+}
+
+impl fmt::Display for MemoryStats {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Memory used by each phase of a day
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DayMemory {
+    pub parse: MemoryStats,
+    pub part1: MemoryStats,
+    pub part2: MemoryStats,
+}
+
+impl DayMemory {
+    /// The peak of the day and the totals of every phase
+    #[inline]
+    #[must_use]
+    pub fn total(&self) -> MemoryStats {
+        let phases = [self.parse, self.part1, self.part2];
+        MemoryStats {
+            allocations: phases.iter().map(|phase| phase.allocations).sum(),
+            bytes: phases.iter().map(|phase| phase.bytes).sum(),
+            peak_bytes: phases
+                .iter()
+                .map(|phase| phase.peak_bytes)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Measures the allocations made on the current thread during each phase
+///
+/// Allocations made by threads a solution spawns itself are not counted.
+#[derive(Debug)]
+pub struct MemoryObserver {
+    /// Live bytes when the observer was created, which peaks are measured from
+    baseline: i64,
+    start: Counters,
+    pub memory: DayMemory,
+}
+
+impl MemoryObserver {
+    pub fn new() -> Self {
+        let start = get_counters();
+        Self {
+            baseline: start.live,
+            start,
+            memory: DayMemory::default(),
+        }
+    }
+}
+
+impl PhaseObserver for MemoryObserver {
+    fn before(&mut self, _phase: Phase) {
+        update_counters(|counters| counters.peak = counters.live);
+        self.start = get_counters();
+    }
+
+    fn after(&mut self, phase: Phase) {
+        let end = get_counters();
+        let stats = MemoryStats {
+            allocations: end.allocations - self.start.allocations,
+            bytes: end.bytes - self.start.bytes,
+            peak_bytes: u64::try_from(end.peak - self.baseline).unwrap_or(0),
+        };
+        match phase {
+            Phase::Parse => self.memory.parse = stats,
+            Phase::Part1 => self.memory.part1 = stats,
+            Phase::Part2 => self.memory.part2 = stats,
+        }
+    }
+}
+
+/// Format a number of bytes using binary units
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{value:.2} {unit}")
+}
+
+#[cfg(feature = "memory")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use super::update_counters;
+
+    /// The system allocator, counting the allocations of each thread
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn record_allocation(size: usize) {
+        update_counters(|counters| {
+            counters.allocations += 1;
+            counters.bytes += size as u64;
+            counters.live += size as i64;
+            counters.peak = counters.peak.max(counters.live);
+        });
+    }
+
+    fn record_deallocation(size: usize) {
+        update_counters(|counters| counters.live -= size as i64);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        #[inline]
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record_allocation(layout.size());
+            }
+            ptr
+        }
+
+        #[inline]
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc_zeroed`
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                record_allocation(layout.size());
+            }
+            ptr
+        }
+
+        #[inline]
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`
+            unsafe { System.dealloc(ptr, layout) };
+            record_deallocation(layout.size());
+        }
+
+        #[inline]
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                record_deallocation(layout.size());
+                record_allocation(new_size);
+            }
+            new_ptr
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        let data: [(u64, &str); 4] = [
+            (0, "0 B"),
+            (1023, "1023 B"),
+            (1536, "1.50 KiB"),
+            (5 * 1024 * 1024 * 1024, "5.00 GiB"),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(format_bytes(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_memory_observer() {
+        let mut observer = MemoryObserver::new();
+        observer.before(Phase::Parse);
+        let values: Vec<u64> = (0..1024).collect();
+        observer.after(Phase::Parse);
+        drop(std::hint::black_box(values));
+
+        let stats = observer.memory.parse;
+        if ENABLED {
+            assert!(stats.allocations >= 1);
+            assert!(stats.bytes >= 8 * 1024);
+            assert!(stats.peak_bytes >= 8 * 1024);
+        } else {
+            assert_eq!(stats, MemoryStats::default());
+        }
+    }
+}
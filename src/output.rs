@@ -0,0 +1,571 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::answers::Verdict;
+use super::memory::{DayMemory, MemoryStats};
+
+/// The format results are written to standard output in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Human readable verification results and failure summary
+    Text,
+    /// One JSON object per line for each day
+    Json,
+    /// One CSV row for each day after a header row
+    Csv,
+    /// A JUnit XML report with one test case per day, written once every day has run
+    Junit,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "junit" => Ok(Self::Junit),
+            other => Err(format!("Invalid output format: {other}")),
+        }
+    }
+}
+
+/// The overall status of a single day
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Status {
+    #[default]
+    Ok,
+    Fail,
+    Panic,
+    Timeout,
+    /// The solution reported that its input could not be parsed
+    InvalidInput,
+    MissingInput,
+    MissingSolution,
+}
+
+impl fmt::Display for Status {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Ok => "OK",
+            Self::Fail => "FAIL",
+            Self::Panic => "PANIC",
+            Self::Timeout => "TIMEOUT",
+            Self::InvalidInput => "INVALID_INPUT",
+            Self::MissingInput => "MISSING_INPUT",
+            Self::MissingSolution => "MISSING_SOLUTION",
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "OK" => Ok(Self::Ok),
+            "FAIL" => Ok(Self::Fail),
+            "PANIC" => Ok(Self::Panic),
+            "TIMEOUT" => Ok(Self::Timeout),
+            "INVALID_INPUT" => Ok(Self::InvalidInput),
+            "MISSING_INPUT" => Ok(Self::MissingInput),
+            "MISSING_SOLUTION" => Ok(Self::MissingSolution),
+            other => Err(format!("Invalid status: {other}")),
+        }
+    }
+}
+
+impl Status {
+    /// Whether the status should fail the run
+    #[inline]
+    #[must_use]
+    pub const fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Self::Fail | Self::Panic | Self::Timeout | Self::InvalidInput
+        )
+    }
+}
+
+/// The structured result of running a single day
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DayRecord {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the input the day ran against, `None` for the default input
+    pub input: Option<String>,
+    pub status: Status,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub part1_verdict: Option<Verdict>,
+    pub part2_verdict: Option<Verdict>,
+    pub parse_time: Option<Duration>,
+    pub part1_time: Option<Duration>,
+    pub part2_time: Option<Duration>,
+    /// Memory used by each phase, if the runner counts allocations
+    pub memory: Option<DayMemory>,
+    pub message: Option<String>,
+}
+
+impl DayRecord {
+    #[inline]
+    #[must_use]
+    pub fn new(year: u16, day: u8, status: Status) -> Self {
+        Self {
+            year,
+            day,
+            status,
+            ..Self::default()
+        }
+    }
+
+    /// The day and the input it ran against, e.g. `day 08 (stress.txt)`
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> String {
+        match &self.input {
+            Some(input) => format!("day {:02} ({input})", self.day),
+            None => format!("day {:02}", self.day),
+        }
+    }
+
+    /// Whether the day should fail the run, including a day whose answers were to be
+    /// verified but whose input is missing
+    #[inline]
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        self.status.is_failure()
+            || (self.status == Status::MissingInput && self.part1_verdict.is_some())
+    }
+
+    /// The combined time of every phase that ran
+    #[inline]
+    #[must_use]
+    pub fn total_time(&self) -> Option<Duration> {
+        let times = [self.parse_time, self.part1_time, self.part2_time];
+        if times.iter().all(Option::is_none) {
+            return None;
+        }
+        Some(times.iter().flatten().sum())
+    }
+}
+
+/// Writes day records to standard output in the selected format
+pub struct ResultWriter {
+    format: OutputFormat,
+    records: Vec<DayRecord>,
+}
+
+impl ResultWriter {
+    pub fn new(format: OutputFormat) -> Self {
+        if format == OutputFormat::Csv {
+            println!("{CSV_HEADER}");
+        }
+        Self {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    pub const fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    pub fn write(&mut self, record: DayRecord) {
+        match self.format {
+            OutputFormat::Text => {
+                let verdicts = [(1, record.part1_verdict), (2, record.part2_verdict)];
+                for (part, verdict) in verdicts {
+                    if let Some(verdict) = verdict {
+                        println!(
+                            "{:04} {} part {part}: {verdict}",
+                            record.year,
+                            record.name()
+                        );
+                    }
+                }
+            }
+            OutputFormat::Json => println!("{}", format_json(&record)),
+            OutputFormat::Csv => println!("{}", format_csv(&record)),
+            OutputFormat::Junit => {}
+        }
+        self.records.push(record);
+    }
+
+    /// Write anything that needs every record and return the number of failed days
+    ///
+    /// The failure summary goes to standard output for text and to standard error for the
+    /// other formats, so it does not mix with the structured output.
+    pub fn finish(self) -> usize {
+        let failures: Vec<&DayRecord> = self
+            .records
+            .iter()
+            .filter(|record| record.is_failure())
+            .collect();
+
+        if self.format == OutputFormat::Junit {
+            print!("{}", format_junit(&self.records));
+        }
+        if !failures.is_empty() {
+            let summary = format_failures(&failures);
+            if self.format == OutputFormat::Text {
+                print!("{summary}");
+            } else {
+                eprint!("{summary}");
+            }
+        }
+
+        failures.len()
+    }
+}
+
+/// A table of the failed days and why each of them failed
+fn format_failures(failures: &[&DayRecord]) -> String {
+    let mut output = format!("{} failure(s):\n", failures.len());
+    output += &format!("{:<4} {:<3} {:<13} Message\n", "Year", "Day", "Status");
+    for record in failures {
+        let message = record.message.as_deref().unwrap_or_default();
+        output += &format!(
+            "{:<4} {:<3} {:<13} {}\n",
+            record.year,
+            record.day,
+            record.status,
+            record.input.as_ref().map_or_else(
+                || message.to_string(),
+                |input| format!("({input}) {message}")
+            )
+        );
+    }
+    output
+}
+
+const CSV_HEADER: &str = "year,day,input,status,part1,part2,part1_verdict,part2_verdict,parse_ns,part1_ns,part2_ns,total_ns,parse_allocations,parse_bytes,parse_peak_bytes,part1_allocations,part1_bytes,part1_peak_bytes,part2_allocations,part2_bytes,part2_peak_bytes,message";
+
+fn format_json(record: &DayRecord) -> String {
+    let string = |value: Option<&str>| value.map_or_else(|| "null".to_string(), json_string);
+    let verdict = |value: Option<Verdict>| string(value.map(|v| v.to_string()).as_deref());
+    let nanos = |value: Option<Duration>| {
+        value.map_or_else(|| "null".to_string(), |v| v.as_nanos().to_string())
+    };
+
+    format!(
+        "{{\"year\":{},\"day\":{},\"input\":{},\"status\":{},\"part1\":{},\"part2\":{},\"part1_verdict\":{},\"part2_verdict\":{},\"parse_ns\":{},\"part1_ns\":{},\"part2_ns\":{},\"total_ns\":{},\"memory\":{},\"message\":{}}}",
+        record.year,
+        record.day,
+        string(record.input.as_deref()),
+        json_string(&record.status.to_string()),
+        string(record.part1.as_deref()),
+        string(record.part2.as_deref()),
+        verdict(record.part1_verdict),
+        verdict(record.part2_verdict),
+        nanos(record.parse_time),
+        nanos(record.part1_time),
+        nanos(record.part2_time),
+        nanos(record.total_time()),
+        record
+            .memory
+            .map_or_else(|| "null".to_string(), |memory| format_json_memory(&memory)),
+        string(record.message.as_deref())
+    )
+}
+
+fn format_json_memory(memory: &DayMemory) -> String {
+    let phase = |stats: &MemoryStats| {
+        format!(
+            "{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+            stats.allocations, stats.bytes, stats.peak_bytes
+        )
+    };
+    format!(
+        "{{\"parse\":{},\"part1\":{},\"part2\":{}}}",
+        phase(&memory.parse),
+        phase(&memory.part1),
+        phase(&memory.part2)
+    )
+}
+
+fn format_csv(record: &DayRecord) -> String {
+    let string = |value: Option<&str>| value.map(csv_field).unwrap_or_default();
+    let verdict = |value: Option<Verdict>| value.map(|v| v.to_string()).unwrap_or_default();
+    let nanos =
+        |value: Option<Duration>| value.map(|v| v.as_nanos().to_string()).unwrap_or_default();
+
+    [
+        record.year.to_string(),
+        record.day.to_string(),
+        string(record.input.as_deref()),
+        record.status.to_string(),
+        string(record.part1.as_deref()),
+        string(record.part2.as_deref()),
+        verdict(record.part1_verdict),
+        verdict(record.part2_verdict),
+        nanos(record.parse_time),
+        nanos(record.part1_time),
+        nanos(record.part2_time),
+        nanos(record.total_time()),
+    ]
+    .into_iter()
+    .chain(memory_fields(record.memory))
+    .chain([string(record.message.as_deref())])
+    .collect::<Vec<String>>()
+    .join(",")
+}
+
+/// The allocation count, bytes and peak of each phase, or empty fields without memory stats
+fn memory_fields(memory: Option<DayMemory>) -> Vec<String> {
+    let Some(memory) = memory else {
+        return vec![String::new(); 9];
+    };
+    [memory.parse, memory.part1, memory.part2]
+        .iter()
+        .flat_map(|stats| [stats.allocations, stats.bytes, stats.peak_bytes])
+        .map(|value| value.to_string())
+        .collect()
+}
+
+/// Format every record as a JUnit report with one test suite per year
+fn format_junit(records: &[DayRecord]) -> String {
+    let mut years: BTreeMap<u16, Vec<&DayRecord>> = BTreeMap::new();
+    for record in records {
+        years.entry(record.year).or_default().push(record);
+    }
+
+    let seconds = |value: Option<Duration>| value.unwrap_or_default().as_secs_f64();
+    let count = |records: &[&DayRecord], status: &[Status], failed: bool| {
+        records
+            .iter()
+            .filter(|record| status.contains(&record.status) && record.is_failure() == failed)
+            .count()
+    };
+
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    for (year, records) in years {
+        output += &format!(
+            "  <testsuite name=\"{year}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n",
+            records.len(),
+            count(&records, &[Status::Fail, Status::MissingInput], true),
+            count(
+                &records,
+                &[Status::Panic, Status::Timeout, Status::InvalidInput],
+                true
+            ),
+            count(
+                &records,
+                &[Status::MissingInput, Status::MissingSolution],
+                false
+            ),
+            records
+                .iter()
+                .map(|record| seconds(record.total_time()))
+                .sum::<f64>()
+        );
+        for record in records {
+            output += &format!(
+                "    <testcase classname=\"{year}\" name=\"{}\" time=\"{:.6}\"",
+                xml_escape(&record.name()),
+                seconds(record.total_time())
+            );
+            let message = xml_escape(record.message.as_deref().unwrap_or_default());
+            let answers = xml_escape(&format!(
+                "part1: {}\npart2: {}",
+                record.part1.as_deref().unwrap_or_default(),
+                record.part2.as_deref().unwrap_or_default()
+            ));
+            let mut children = record.memory.map(format_junit_memory).unwrap_or_default();
+            match record.status {
+                Status::Ok => {}
+                Status::Fail | Status::MissingInput if record.is_failure() => {
+                    children +=
+                        &format!("      <failure message=\"{message}\">{answers}</failure>\n");
+                }
+                Status::Panic | Status::Timeout | Status::InvalidInput => {
+                    children += &format!(
+                        "      <error type=\"{}\" message=\"{message}\"/>\n",
+                        record.status
+                    );
+                }
+                // `Fail` is always a failure, so only days that did not run reach this arm
+                Status::Fail | Status::MissingInput | Status::MissingSolution => {
+                    children += &format!("      <skipped message=\"{message}\"/>\n");
+                }
+            }
+            if children.is_empty() {
+                output += "/>\n";
+            } else {
+                output += &format!(">\n{children}    </testcase>\n");
+            }
+        }
+        output += "  </testsuite>\n";
+    }
+    output += "</testsuites>\n";
+    output
+}
+
+/// Memory stats as test case properties
+fn format_junit_memory(memory: DayMemory) -> String {
+    let mut output = String::from("      <properties>\n");
+    for (phase, stats) in [
+        ("parse", memory.parse),
+        ("part1", memory.part1),
+        ("part2", memory.part2),
+    ] {
+        for (name, value) in [
+            ("allocations", stats.allocations),
+            ("bytes", stats.bytes),
+            ("peak_bytes", stats.peak_bytes),
+        ] {
+            output += &format!("        <property name=\"{phase}_{name}\" value=\"{value}\"/>\n");
+        }
+    }
+    output + "      </properties>\n"
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escape a value for use in XML or HTML text and attribute values
+pub fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved_record() -> DayRecord {
+        DayRecord {
+            part1: Some("42".to_string()),
+            part2: Some("#.\n.#".to_string()),
+            part1_verdict: Some(Verdict::Pass),
+            part2_verdict: Some(Verdict::Fail),
+            parse_time: Some(Duration::from_nanos(100)),
+            part1_time: Some(Duration::from_nanos(200)),
+            part2_time: Some(Duration::from_nanos(300)),
+            memory: Some(DayMemory {
+                parse: MemoryStats {
+                    allocations: 3,
+                    bytes: 4096,
+                    peak_bytes: 2048,
+                },
+                ..DayMemory::default()
+            }),
+            message: Some("Part 2 answer does not match".to_string()),
+            ..DayRecord::new(2019, 8, Status::Fail)
+        }
+    }
+
+    #[test]
+    fn test_format_json() {
+        assert_eq!(
+            format_json(&solved_record()),
+            "{\"year\":2019,\"day\":8,\"input\":null,\"status\":\"FAIL\",\"part1\":\"42\",\"part2\":\"#.\\n.#\",\"part1_verdict\":\"PASS\",\"part2_verdict\":\"FAIL\",\"parse_ns\":100,\"part1_ns\":200,\"part2_ns\":300,\"total_ns\":600,\"memory\":{\"parse\":{\"allocations\":3,\"bytes\":4096,\"peak_bytes\":2048},\"part1\":{\"allocations\":0,\"bytes\":0,\"peak_bytes\":0},\"part2\":{\"allocations\":0,\"bytes\":0,\"peak_bytes\":0}},\"message\":\"Part 2 answer does not match\"}"
+        );
+        assert_eq!(
+            format_json(&DayRecord {
+                input: Some("alice".to_string()),
+                ..DayRecord::new(2017, 1, Status::MissingInput)
+            }),
+            "{\"year\":2017,\"day\":1,\"input\":\"alice\",\"status\":\"MISSING_INPUT\",\"part1\":null,\"part2\":null,\"part1_verdict\":null,\"part2_verdict\":null,\"parse_ns\":null,\"part1_ns\":null,\"part2_ns\":null,\"total_ns\":null,\"memory\":null,\"message\":null}"
+        );
+    }
+
+    #[test]
+    fn test_format_csv() {
+        assert_eq!(
+            format_csv(&solved_record()),
+            "2019,8,,FAIL,42,\"#.\n.#\",PASS,FAIL,100,200,300,600,3,4096,2048,0,0,0,0,0,0,Part 2 answer does not match"
+        );
+        assert_eq!(
+            CSV_HEADER.split(',').count(),
+            format_csv(&DayRecord::new(2017, 1, Status::Timeout))
+                .split(',')
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_format_junit() {
+        let records = [
+            DayRecord::new(2017, 1, Status::Ok),
+            DayRecord {
+                input: Some("<stdin>".to_string()),
+                ..DayRecord::new(2017, 1, Status::Ok)
+            },
+            DayRecord {
+                message: Some("index out of bounds: <3>".to_string()),
+                ..DayRecord::new(2017, 2, Status::Panic)
+            },
+            solved_record(),
+            DayRecord {
+                part1_verdict: Some(Verdict::Unknown),
+                part2_verdict: Some(Verdict::Unknown),
+                message: Some("Could not read file".to_string()),
+                ..DayRecord::new(2018, 1, Status::MissingInput)
+            },
+            DayRecord::new(2018, 2, Status::MissingInput),
+        ];
+        let output = format_junit(&records);
+
+        assert!(output.contains(
+            "<testsuite name=\"2017\" tests=\"3\" failures=\"0\" errors=\"1\" skipped=\"0\""
+        ));
+        // A missing input fails verification but is skipped otherwise
+        assert!(output.contains(
+            "<testsuite name=\"2018\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"1\""
+        ));
+        assert!(output.contains("<failure message=\"Could not read file\">"));
+        assert!(
+            output.contains("<testcase classname=\"2017\" name=\"day 01\" time=\"0.000000\"/>")
+        );
+        assert!(output.contains(
+            "<testcase classname=\"2017\" name=\"day 01 (&lt;stdin&gt;)\" time=\"0.000000\"/>"
+        ));
+        assert!(
+            output.contains("<error type=\"PANIC\" message=\"index out of bounds: &lt;3&gt;\"/>")
+        );
+        assert!(output.contains(
+            "<failure message=\"Part 2 answer does not match\">part1: 42\npart2: #.\n.#</failure>"
+        ));
+        assert!(output.contains("<property name=\"parse_peak_bytes\" value=\"2048\"/>"));
+    }
+
+    #[test]
+    fn test_output_format_from_str() {
+        assert_eq!("junit".parse::<OutputFormat>(), Ok(OutputFormat::Junit));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
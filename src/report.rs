@@ -0,0 +1,499 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs::{create_dir_all, write};
+use std::io::Error;
+use std::time::Duration;
+
+use log::trace;
+
+use super::answers::{Answers, Verdict, get_answers_path, read_answers};
+use super::args::Mode;
+use super::history::{HISTORY_PATH, RunEntry, read_history};
+use super::inputs::DayInput;
+use super::output::{DayRecord, OutputFormat, Status, xml_escape};
+use super::results::handle_report;
+use super::runner::run_days;
+use super::years::{YEARS, get_days, get_puzzle_count};
+
+/// Directory the generated reports are written to
+pub const REPORT_DIR: &str = "reports";
+/// Number of characters used for the runtime bar of the slowest day
+const BAR_WIDTH: usize = 20;
+
+/// The state of one part of a day in the completion grid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Cell {
+    /// The answer matches the recorded answer
+    Verified,
+    /// The answer differs from the recorded answer, or the day did not finish
+    Failed,
+    /// Solved without a recorded answer to compare against
+    Unverified,
+    /// A solution exists but has no result
+    NotRun,
+    /// No solution is registered for the day
+    Missing,
+}
+
+impl Cell {
+    const fn symbol(self) -> &'static str {
+        match self {
+            Self::Verified => "✅",
+            Self::Failed => "❌",
+            Self::Unverified => "❔",
+            Self::NotRun => "⏸",
+            Self::Missing => "·",
+        }
+    }
+
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Verified => "verified",
+            Self::Failed => "failed",
+            Self::Unverified => "unverified",
+            Self::NotRun => "not-run",
+            Self::Missing => "missing",
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Verified => "verified",
+            Self::Failed => "failed",
+            Self::Unverified => "not verified",
+            Self::NotRun => "not run",
+            Self::Missing => "not solved",
+        }
+    }
+}
+
+/// The results of a single year
+#[derive(Clone, Debug)]
+pub struct YearReport {
+    pub year: u16,
+    /// Days with a registered solution
+    solved: Vec<u8>,
+    /// Results ordered from the slowest day to the fastest
+    records: Vec<DayRecord>,
+}
+
+impl YearReport {
+    fn new(year: u16, records: &[DayRecord]) -> Self {
+        let solved: Vec<u8> = get_days(year)
+            .unwrap_or_default()
+            .iter()
+            .map(|registered| registered.day)
+            .collect();
+        let mut records: Vec<DayRecord> = records
+            .iter()
+            .filter(|record| record.year == year)
+            .cloned()
+            .collect();
+        records.sort_by(|a, b| b.total_time().cmp(&a.total_time()).then(a.day.cmp(&b.day)));
+        Self {
+            year,
+            solved,
+            records,
+        }
+    }
+
+    fn cell(&self, day: u8, part: u8) -> Cell {
+        if !self.solved.contains(&day) {
+            return Cell::Missing;
+        }
+        let Some(record) = self.records.iter().find(|record| record.day == day) else {
+            return Cell::NotRun;
+        };
+        if matches!(
+            record.status,
+            Status::Panic | Status::Timeout | Status::InvalidInput
+        ) {
+            return Cell::Failed;
+        }
+        if matches!(
+            record.status,
+            Status::MissingInput | Status::MissingSolution
+        ) {
+            return Cell::NotRun;
+        }
+        let verdict = if part == 1 {
+            record.part1_verdict
+        } else {
+            record.part2_verdict
+        };
+        match verdict {
+            Some(Verdict::Pass) => Cell::Verified,
+            Some(Verdict::Fail) => Cell::Failed,
+            Some(Verdict::Unknown) | None => Cell::Unverified,
+        }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        (1..=get_puzzle_count(self.year))
+            .flat_map(move |day| [1, 2].map(|part| self.cell(day, part)))
+    }
+
+    fn total_time(&self) -> Duration {
+        self.records.iter().filter_map(DayRecord::total_time).sum()
+    }
+
+    /// Fraction of the slowest day of the year taken by a day
+    fn bar_fraction(&self, record: &DayRecord) -> f64 {
+        let slowest = self
+            .records
+            .iter()
+            .filter_map(DayRecord::total_time)
+            .max()
+            .unwrap_or_default();
+        match record.total_time() {
+            Some(time) if !slowest.is_zero() => time.as_secs_f64() / slowest.as_secs_f64(),
+            _ => 0.0,
+        }
+    }
+
+    fn summary(&self) -> String {
+        let verified = self.cells().filter(|&cell| cell == Cell::Verified).count();
+        format!(
+            "{}/{} days solved, {verified}/{} parts verified, total runtime {:.2?}",
+            self.solved.len(),
+            get_puzzle_count(self.year),
+            usize::from(get_puzzle_count(self.year)) * 2,
+            self.total_time()
+        )
+    }
+}
+
+/// Run the solved days of the selected years, or read their latest runs from the history,
+/// and write the Markdown and HTML reports
+///
+/// Returns the paths of the written files.
+///
+/// # Errors
+///
+/// Will return `Err` if the history cannot be read or the reports cannot be written
+pub fn create_report(
+    year: Option<u16>,
+    from_history: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Result<Vec<String>, String> {
+    let years: Vec<u16> = year.map_or_else(
+        || YEARS.iter().map(|&(year, _)| year).collect(),
+        |year| vec![year],
+    );
+    let records = if from_history {
+        let entries = read_history()
+            .map_err(|error| format!("Could not read history at {HISTORY_PATH}: {error}"))?;
+        get_history_records(&entries, &years)
+    } else {
+        run_years(&years, jobs, timeout)
+    };
+
+    let reports: Vec<YearReport> = years
+        .iter()
+        .map(|&year| YearReport::new(year, &records))
+        .collect();
+    write_reports(&reports)
+        .map_err(|error| format!("Could not write reports to {REPORT_DIR}: {error}"))
+}
+
+/// Run every solved day of the years against the default inputs and verify the answers
+fn run_years(years: &[u16], jobs: usize, timeout: Option<Duration>) -> Vec<DayRecord> {
+    let inputs: Vec<DayInput> = years
+        .iter()
+        .flat_map(|&year| {
+            get_days(year)
+                .unwrap_or_default()
+                .iter()
+                .map(move |registered| DayInput::new(year, registered.day))
+        })
+        .collect();
+    let mut records: Vec<DayRecord> = Vec::new();
+    run_days(&inputs, jobs, timeout, |report| {
+        records.push(handle_report(report, Mode::Verify, OutputFormat::Text));
+    });
+    records
+}
+
+/// The latest recorded run of each day of the years, verified against the current answers
+fn get_history_records(entries: &[RunEntry], years: &[u16]) -> Vec<DayRecord> {
+    let mut latest: BTreeMap<(u16, u8), &RunEntry> = BTreeMap::new();
+    for entry in entries.iter().filter(|entry| years.contains(&entry.year)) {
+        latest.insert((entry.year, entry.day), entry);
+    }
+
+    latest
+        .into_values()
+        .map(|entry| {
+            let mut record = DayRecord {
+                parse_time: entry.parse_time,
+                part1_time: entry.part1_time,
+                part2_time: entry.part2_time,
+                part1: entry.part1.clone(),
+                part2: entry.part2.clone(),
+                ..DayRecord::new(entry.year, entry.day, entry.status)
+            };
+            if let (Some(part1), Some(part2)) = (&entry.part1, &entry.part2) {
+                let answers = read_answers(entry.year, entry.day).unwrap_or_else(|_| {
+                    trace!(
+                        "No answers recorded at `{}`",
+                        get_answers_path(entry.year, entry.day)
+                    );
+                    Answers::default()
+                });
+                let (part1_verdict, part2_verdict) = answers.verify(part1, part2);
+                record.part1_verdict = Some(part1_verdict);
+                record.part2_verdict = Some(part2_verdict);
+            }
+            record
+        })
+        .collect()
+}
+
+fn write_reports(reports: &[YearReport]) -> Result<Vec<String>, Error> {
+    create_dir_all(REPORT_DIR)?;
+    let markdown_path = format!("{REPORT_DIR}/results.md");
+    write(&markdown_path, format_markdown(reports))?;
+    let html_path = format!("{REPORT_DIR}/results.html");
+    write(&html_path, format_html(reports))?;
+    Ok(vec![markdown_path, html_path])
+}
+
+fn format_markdown(reports: &[YearReport]) -> String {
+    let mut result = String::from("# Advent of Code results\n");
+    let legend: Vec<String> = [
+        Cell::Verified,
+        Cell::Failed,
+        Cell::Unverified,
+        Cell::NotRun,
+        Cell::Missing,
+    ]
+    .iter()
+    .map(|cell| format!("{} {}", cell.symbol(), cell.description()))
+    .collect();
+    let _ = writeln!(result, "\n{}", legend.join(", "));
+
+    for report in reports {
+        let puzzle_count = get_puzzle_count(report.year);
+        let _ = writeln!(result, "\n## {}\n\n{}\n", report.year, report.summary());
+
+        let days: Vec<String> = (1..=puzzle_count).map(|day| day.to_string()).collect();
+        let _ = writeln!(result, "| Part | {} |", days.join(" | "));
+        let _ = writeln!(result, "|---|{}", ":-:|".repeat(days.len()));
+        for part in [1, 2] {
+            let cells: Vec<&str> = (1..=puzzle_count)
+                .map(|day| report.cell(day, part).symbol())
+                .collect();
+            let _ = writeln!(result, "| {part} | {} |", cells.join(" | "));
+        }
+
+        if report.records.is_empty() {
+            continue;
+        }
+        result.push_str("\n| Day | Status | Part 1 | Part 2 | Runtime | |\n");
+        result.push_str("|--:|---|:-:|:-:|--:|---|\n");
+        for record in &report.records {
+            let filled = (report.bar_fraction(record) * BAR_WIDTH as f64).round() as usize;
+            let _ = writeln!(
+                result,
+                "| {} | {} | {} | {} | {} | `{}{}` |",
+                record.day,
+                record.status,
+                report.cell(record.day, 1).symbol(),
+                report.cell(record.day, 2).symbol(),
+                format_time(record.total_time()),
+                "█".repeat(filled),
+                " ".repeat(BAR_WIDTH - filled)
+            );
+        }
+        let _ = writeln!(
+            result,
+            "| **Total** | | | | **{:.2?}** | |",
+            report.total_time()
+        );
+    }
+    result
+}
+
+fn format_html(reports: &[YearReport]) -> String {
+    let mut result = String::from(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code results</title>
+<style>
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { padding: 0.2em 0.5em; text-align: left; }
+.grid td { width: 1.4em; height: 1.4em; padding: 0; border: 1px solid #fff; }
+.verified { background: #2e7d32; }
+.failed { background: #c62828; }
+.unverified { background: #f9a825; }
+.not-run { background: #90a4ae; }
+.missing { background: #eceff1; }
+.runtime td.time, .runtime th.time { text-align: right; }
+.bar { background: #1565c0; height: 0.8em; }
+.legend span { display: inline-block; width: 1em; height: 1em; margin: 0 0.3em 0 1em; vertical-align: middle; }
+</style>
+</head>
+<body>
+<h1>Advent of Code results</h1>
+<p class=\"legend\">",
+    );
+    for cell in [
+        Cell::Verified,
+        Cell::Failed,
+        Cell::Unverified,
+        Cell::NotRun,
+        Cell::Missing,
+    ] {
+        let _ = write!(
+            result,
+            "<span class=\"{}\"></span>{}",
+            cell.class(),
+            cell.description()
+        );
+    }
+    result.push_str("</p>\n");
+
+    for report in reports {
+        let puzzle_count = get_puzzle_count(report.year);
+        let _ = writeln!(
+            result,
+            "<h2>{}</h2>\n<p>{}</p>",
+            report.year,
+            xml_escape(&report.summary())
+        );
+
+        result.push_str("<table class=\"grid\">\n<tr><th>Part</th>");
+        for day in 1..=puzzle_count {
+            let _ = write!(result, "<th>{day}</th>");
+        }
+        result.push_str("</tr>\n");
+        for part in [1, 2] {
+            let _ = write!(result, "<tr><th>{part}</th>");
+            for day in 1..=puzzle_count {
+                let cell = report.cell(day, part);
+                let _ = write!(
+                    result,
+                    "<td class=\"{}\" title=\"Day {day} part {part}: {}\"></td>",
+                    cell.class(),
+                    cell.description()
+                );
+            }
+            result.push_str("</tr>\n");
+        }
+        result.push_str("</table>\n");
+
+        if report.records.is_empty() {
+            continue;
+        }
+        result.push_str(
+            "<table class=\"runtime\">\n<tr><th>Day</th><th>Status</th><th>Part 1</th>\
+             <th>Part 2</th><th class=\"time\">Runtime</th><th></th></tr>\n",
+        );
+        for record in &report.records {
+            let _ = writeln!(
+                result,
+                "<tr><td>{}</td><td>{}</td><td class=\"{}\"></td><td class=\"{}\"></td>\
+                 <td class=\"time\">{}</td>\
+                 <td style=\"width: 20em\"><div class=\"bar\" style=\"width: {:.1}%\"></div></td></tr>",
+                record.day,
+                record.status,
+                report.cell(record.day, 1).class(),
+                report.cell(record.day, 2).class(),
+                xml_escape(&format_time(record.total_time())),
+                report.bar_fraction(record) * 100.0
+            );
+        }
+        let _ = writeln!(
+            result,
+            "<tr><th>Total</th><td></td><td></td><td></td><th class=\"time\">{}</th><td></td></tr>\n</table>",
+            xml_escape(&format!("{:.2?}", report.total_time()))
+        );
+    }
+    result.push_str("</body>\n</html>\n");
+    result
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_string(), |time| format!("{time:.2?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, status: Status, total_ms: u64, verdicts: (Verdict, Verdict)) -> DayRecord {
+        DayRecord {
+            parse_time: Some(Duration::ZERO),
+            part1_time: Some(Duration::from_millis(total_ms)),
+            part2_time: Some(Duration::ZERO),
+            part1_verdict: Some(verdicts.0),
+            part2_verdict: Some(verdicts.1),
+            ..DayRecord::new(2017, day, status)
+        }
+    }
+
+    #[test]
+    fn test_year_report() {
+        let records = [
+            record(1, Status::Ok, 10, (Verdict::Pass, Verdict::Pass)),
+            record(2, Status::Fail, 40, (Verdict::Pass, Verdict::Fail)),
+            record(3, Status::Ok, 20, (Verdict::Pass, Verdict::Unknown)),
+            DayRecord::new(2017, 4, Status::Timeout),
+        ];
+        let report = YearReport::new(2017, &records);
+
+        let days: Vec<u8> = report.records.iter().map(|record| record.day).collect();
+        assert_eq!(days, vec![2, 3, 1, 4]);
+        assert_eq!(report.total_time(), Duration::from_millis(70));
+
+        let data: [((u8, u8), Cell); 6] = [
+            ((1, 2), Cell::Verified),
+            ((2, 2), Cell::Failed),
+            ((3, 2), Cell::Unverified),
+            ((4, 1), Cell::Failed),
+            ((5, 1), Cell::NotRun),
+            ((26, 1), Cell::Missing),
+        ];
+        for ((day, part), expected) in data {
+            assert_eq!(report.cell(day, part), expected);
+        }
+        assert!((report.bar_fraction(&records[2]) - 0.5).abs() < f64::EPSILON);
+
+        let markdown = format_markdown(&[report]);
+        assert!(markdown.contains(
+            "\n## 2017\n\n25/25 days solved, 4/50 parts verified, total runtime 70.00ms\n"
+        ));
+        assert!(markdown.contains("| 2 | FAIL | ✅ | ❌ | 40.00ms | `████████████████████` |"));
+        assert!(markdown.contains("| **Total** | | | | **70.00ms** | |"));
+    }
+
+    #[test]
+    fn test_get_history_records() {
+        let entry = |time: u64, year: u16, part1: &str| RunEntry {
+            time,
+            revision: "abc".to_string(),
+            year,
+            day: 1,
+            status: Status::Ok,
+            parse_time: Some(Duration::from_millis(1)),
+            part1_time: Some(Duration::from_millis(1)),
+            part2_time: Some(Duration::from_millis(1)),
+            part1: Some(part1.to_string()),
+            part2: Some("2".to_string()),
+        };
+        let entries = [
+            entry(1, 2099, "old"),
+            entry(2, 2099, "new"),
+            entry(3, 2098, "other"),
+        ];
+
+        let records = get_history_records(&entries, &[2099]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].part1.as_deref(), Some("new"));
+        assert_eq!(records[0].part1_verdict, Some(Verdict::Unknown));
+    }
+}
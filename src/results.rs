@@ -0,0 +1,139 @@
+use log::{debug, error, info, trace, warn};
+
+use super::answers::{Answers, Verdict, get_answers_path, read_answers, write_answers};
+use super::args::Mode;
+use super::output::{DayRecord, OutputFormat, Status};
+use super::runner::{DayReport, Outcome};
+
+/// Logs the result of a single day and converts it into a structured record
+pub fn handle_report(report: DayReport, mode: Mode, format: OutputFormat) -> DayRecord {
+    let DayReport {
+        year,
+        day,
+        label,
+        outcome,
+        memory,
+        ..
+    } = report;
+    let name = label.as_ref().map_or_else(
+        || format!("Day {day:02}"),
+        |label| format!("Day {day:02} ({label})"),
+    );
+    let record = match outcome {
+        Outcome::Solved(result) => {
+            debug!("File parse: ({:?})", result.parse_time);
+            debug!("Part 1: {} ({:?})", result.part1, result.part1_time);
+            debug!("Part 2: {} ({:?})", result.part2, result.part2_time);
+            info!(
+                "{}: ({}, {}) ({:?})",
+                name,
+                result.part1,
+                result.part2,
+                result.total_time()
+            );
+            if let Some(memory) = memory {
+                debug!("File parse memory: {}", memory.parse);
+                debug!("Part 1 memory: {}", memory.part1);
+                debug!("Part 2 memory: {}", memory.part2);
+                info!("{name} memory: {}", memory.total());
+            }
+
+            let mut record = DayRecord {
+                parse_time: Some(result.parse_time),
+                part1_time: Some(result.part1_time),
+                part2_time: Some(result.part2_time),
+                memory,
+                ..DayRecord::new(year, day, Status::Ok)
+            };
+            match mode {
+                Mode::Run => {}
+                Mode::Verify => {
+                    let (part1_verdict, part2_verdict) =
+                        verify_answers(year, day, &result.part1, &result.part2);
+                    let failed: Vec<String> = [(1, part1_verdict), (2, part2_verdict)]
+                        .into_iter()
+                        .filter(|&(_, verdict)| verdict == Verdict::Fail)
+                        .map(|(part, _)| format!("Part {part} answer does not match"))
+                        .collect();
+                    if !failed.is_empty() {
+                        record.status = Status::Fail;
+                        record.message = Some(failed.join("; "));
+                    }
+                    record.part1_verdict = Some(part1_verdict);
+                    record.part2_verdict = Some(part2_verdict);
+                }
+                Mode::Record => {
+                    record_answers(year, day, &result.part1, &result.part2, format);
+                }
+            }
+            record.part1 = Some(result.part1);
+            record.part2 = Some(result.part2);
+            record
+        }
+        Outcome::MissingInput(file_path) => {
+            warn!("Could not read file at {file_path}");
+            // Answers that cannot be computed cannot be verified either
+            let verdict = (mode == Mode::Verify).then_some(Verdict::Unknown);
+            DayRecord {
+                part1_verdict: verdict,
+                part2_verdict: verdict,
+                message: Some(format!("Could not read file at {file_path}")),
+                ..DayRecord::new(year, day, Status::MissingInput)
+            }
+        }
+        Outcome::MissingSolution => {
+            warn!("Could not find solution for year {year:04} day {day:02}");
+            DayRecord::new(year, day, Status::MissingSolution)
+        }
+        Outcome::Panicked(message) => {
+            error!("{name}: panicked ({message})");
+            DayRecord {
+                message: Some(message),
+                ..DayRecord::new(year, day, Status::Panic)
+            }
+        }
+        Outcome::TimedOut(timeout) => {
+            error!("{name}: timed out after {timeout:?}");
+            DayRecord {
+                message: Some(format!("Exceeded {timeout:?}")),
+                ..DayRecord::new(year, day, Status::Timeout)
+            }
+        }
+        Outcome::InvalidInput(parse_error) => {
+            error!("{name}: invalid input at {parse_error}");
+            DayRecord {
+                message: Some(format!("Invalid input at {parse_error}")),
+                ..DayRecord::new(year, day, Status::InvalidInput)
+            }
+        }
+    };
+    DayRecord {
+        input: label,
+        ..record
+    }
+}
+
+/// Compares the answers of each part against the recorded answers
+fn verify_answers(year: u16, day: u8, part1: &str, part2: &str) -> (Verdict, Verdict) {
+    let answers = read_answers(year, day).unwrap_or_else(|_| {
+        trace!("No answers recorded at `{}`", get_answers_path(year, day));
+        Answers::default()
+    });
+
+    answers.verify(part1, part2)
+}
+
+fn record_answers(year: u16, day: u8, part1: &str, part2: &str, format: OutputFormat) {
+    let answers = Answers {
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    };
+    let file_path = get_answers_path(year, day);
+    if let Err(error) = write_answers(year, day, &answers) {
+        warn!("Could not write answers to {file_path}: {error}");
+    } else if format == OutputFormat::Text {
+        println!("{year:04} day {day:02}: recorded answers to {file_path}");
+    } else {
+        info!("Recorded answers to {file_path}");
+    }
+}
@@ -0,0 +1,337 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{RecvTimeoutError, channel};
+use std::thread::{Builder, available_parallelism, scope};
+use std::time::{Duration, Instant};
+
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::SolveResult;
+use log::{debug, trace};
+
+use super::inputs::DayInput;
+use super::memory::{self, DayMemory, MemoryObserver};
+use super::years::get_solve_module;
+
+/// Stack size of the thread each day runs on, some solutions recurse deeply
+const DAY_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// The outcome of attempting to run a single day
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Solved(SolveResult),
+    MissingSolution,
+    MissingInput(String),
+    /// The solution panicked with the given message
+    Panicked(String),
+    /// The solution did not finish within the given time
+    TimedOut(Duration),
+    /// The solution could not parse its input
+    InvalidInput(ParseError),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the input the day ran against, `None` for the default input
+    pub label: Option<String>,
+    pub outcome: Outcome,
+    /// Time spent running the day including reading the input
+    pub elapsed: Duration,
+    /// CPU time used by the thread the day ran on, if the platform can measure it and the
+    /// day finished
+    pub cpu_time: Option<Duration>,
+    /// Memory used by each phase, if the runner counts allocations
+    pub memory: Option<DayMemory>,
+}
+
+/// Run a day on its own thread so a panic or a runaway loop does not take down the runner
+pub fn run_day(input: &DayInput, timeout: Option<Duration>) -> DayReport {
+    let day_timer = Instant::now();
+    let DayInput {
+        year, day, label, ..
+    } = input.clone();
+    let job_input = input.clone();
+    let (outcome, memory, cpu_time) =
+        run_isolated(format!("{year:04}-{day:02}"), timeout, move || {
+            let (outcome, memory) = solve_day(&job_input);
+            // The day has the thread to itself, so its CPU time is the day's CPU time
+            (outcome, memory, get_thread_cpu_time())
+        })
+        .unwrap_or_else(|failure| (failure, None, None));
+
+    DayReport {
+        year,
+        day,
+        label,
+        outcome,
+        elapsed: day_timer.elapsed(),
+        cpu_time,
+        memory,
+    }
+}
+
+/// Parse the input for a day once and solve only the given part, isolated like `run_day`
+///
+/// # Errors
+///
+/// Will return `Err` with the outcome if the part could not be solved
+pub fn run_part(input: &DayInput, part: u8, timeout: Option<Duration>) -> Result<String, Outcome> {
+    let DayInput { year, day, .. } = *input;
+    let job_input = input.clone();
+    run_isolated(format!("{year:04}-{day:02}-{part}"), timeout, move || {
+        solve_part(&job_input, part)
+    })
+    .and_then(|result| result)
+}
+
+/// Run a job on a named thread, returning `Outcome::Panicked` or `Outcome::TimedOut` if it
+/// does not finish normally
+///
+/// A job that exceeds the timeout is left running in the background, as threads cannot
+/// be stopped from the outside.
+pub fn run_isolated<T, F>(name: String, timeout: Option<Duration>, job: F) -> Result<T, Outcome>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = channel::<Result<T, Outcome>>();
+
+    let spawned = Builder::new()
+        .name(name)
+        .stack_size(DAY_STACK_SIZE)
+        .spawn(move || {
+            let result = catch_unwind(AssertUnwindSafe(job))
+                .map_err(|payload| Outcome::Panicked(get_panic_message(&*payload)));
+            // The runner stops listening once the job has timed out
+            let _ = sender.send(result);
+        });
+
+    match spawned {
+        Ok(_) => match timeout {
+            Some(timeout) => match receiver.recv_timeout(timeout) {
+                Ok(result) => result,
+                Err(RecvTimeoutError::Timeout) => Err(Outcome::TimedOut(timeout)),
+                Err(RecvTimeoutError::Disconnected) => Err(Outcome::Panicked(
+                    "Solution thread exited without a result".to_string(),
+                )),
+            },
+            None => receiver.recv().unwrap_or_else(|_| {
+                Err(Outcome::Panicked(
+                    "Solution thread exited without a result".to_string(),
+                ))
+            }),
+        },
+        Err(error) => Err(Outcome::Panicked(format!(
+            "Could not start solution thread: {error}"
+        ))),
+    }
+}
+
+/// Read the input for a day and run every phase of its solution
+fn solve_day(input: &DayInput) -> (Outcome, Option<DayMemory>) {
+    let DayInput { year, day, .. } = *input;
+    trace!("Attempting to run year {year:04} day {day:02}");
+
+    if let Ok(solution) = get_solve_module(year, day) {
+        let input_timer = Instant::now();
+        trace!("Attempting to read input {:?}", input.data);
+
+        match input.read() {
+            Ok(file_contents) => {
+                debug!("File read: ({:?})", input_timer.elapsed());
+                let (result, memory) = if memory::ENABLED {
+                    let mut observer = MemoryObserver::new();
+                    let result = solution.solve_observed(&file_contents, &mut observer);
+                    (result, Some(observer.memory))
+                } else {
+                    (solution.solve(&file_contents), None)
+                };
+                match result {
+                    Ok(result) => (Outcome::Solved(result), memory),
+                    Err(error) => (Outcome::InvalidInput(error), memory),
+                }
+            }
+            Err(file_path) => (Outcome::MissingInput(file_path), None),
+        }
+    } else {
+        (Outcome::MissingSolution, None)
+    }
+}
+
+/// Read the input for a day, parse it and solve a single part
+fn solve_part(input: &DayInput, part: u8) -> Result<String, Outcome> {
+    let solution = get_solve_module(input.year, input.day).map_err(|_| Outcome::MissingSolution)?;
+    let file_contents = input.read().map_err(Outcome::MissingInput)?;
+    let parsed = solution
+        .try_parse(&file_contents)
+        .map_err(Outcome::InvalidInput)?;
+    Ok(if part == 1 {
+        parsed.part1()
+    } else {
+        parsed.part2()
+    })
+}
+
+/// CPU time used by the current thread so far
+#[cfg(unix)]
+fn get_thread_cpu_time() -> Option<Duration> {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid timespec for the call to write to
+    let result = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &raw mut time) };
+    if result != 0 {
+        return None;
+    }
+    Some(Duration::new(
+        u64::try_from(time.tv_sec).ok()?,
+        u32::try_from(time.tv_nsec).ok()?,
+    ))
+}
+
+/// CPU time used by the current thread so far
+#[cfg(not(unix))]
+fn get_thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic payload".to_string()
+    }
+}
+
+/// Run each input and pass each report to `on_report` in the order the inputs were given
+///
+/// With more than one job the inputs are spread across worker threads and reports are
+/// buffered until every earlier input has been reported. A job count of 0 uses one
+/// worker per available CPU.
+pub fn run_days<F: FnMut(DayReport)>(
+    days: &[DayInput],
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut on_report: F,
+) {
+    let jobs = match jobs {
+        0 => available_parallelism().map_or(1, |count| count.get()),
+        jobs => jobs,
+    }
+    .min(days.len());
+
+    if jobs <= 1 {
+        for input in days {
+            on_report(run_day(input, timeout));
+        }
+        return;
+    }
+
+    trace!("Running {} days across {jobs} workers", days.len());
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = channel::<(usize, DayReport)>();
+
+    scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let next_day = &next_day;
+            scope.spawn(move || {
+                loop {
+                    let index = next_day.fetch_add(1, Ordering::Relaxed);
+                    let Some(input) = days.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, run_day(input, timeout))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Hold back reports that finished early so they are emitted in order
+        let mut pending: BTreeMap<usize, DayReport> = BTreeMap::new();
+        let mut next_report = 0;
+        for (index, report) in receiver {
+            pending.insert(index, report);
+            while let Some(report) = pending.remove(&next_report) {
+                on_report(report);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_days_order() {
+        let days: Vec<DayInput> = (1..=25).map(|day| DayInput::new(1900, day)).collect();
+
+        for jobs in [1, 4] {
+            let mut reported: Vec<u8> = Vec::new();
+            run_days(&days, jobs, None, |report| {
+                assert_eq!(report.outcome, Outcome::MissingSolution);
+                reported.push(report.day);
+            });
+            assert_eq!(reported, (1..=25).collect::<Vec<u8>>());
+        }
+    }
+
+    #[test]
+    fn test_run_part() {
+        assert_eq!(
+            run_part(&DayInput::new(1900, 1), 1, None),
+            Err(Outcome::MissingSolution)
+        );
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        assert_eq!(run_isolated("ok".to_string(), None, || 42), Ok(42));
+        assert_eq!(
+            run_isolated("panic".to_string(), None, || -> i64 { panic!("boom") }),
+            Err(Outcome::Panicked("boom".to_string()))
+        );
+        let timeout = Duration::from_millis(10);
+        assert_eq!(
+            run_isolated("timeout".to_string(), Some(timeout), || {
+                std::thread::sleep(Duration::from_secs(1));
+            }),
+            Err(Outcome::TimedOut(timeout))
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_get_thread_cpu_time() {
+        let start = get_thread_cpu_time().unwrap();
+        while get_thread_cpu_time().unwrap() - start < Duration::from_millis(5) {}
+        let busy = get_thread_cpu_time().unwrap();
+
+        // Sleeping does not use CPU time
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(get_thread_cpu_time().unwrap() - busy < Duration::from_millis(25));
+    }
+
+    #[test]
+    fn test_get_panic_message() {
+        let data: [(Box<dyn Any + Send>, &str); 3] = [
+            (Box::new("static message"), "static message"),
+            (Box::new("owned message".to_string()), "owned message"),
+            (Box::new(42), "Unknown panic payload"),
+        ];
+
+        for (payload, expected) in data {
+            assert_eq!(get_panic_message(&*payload), expected);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::env;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::Path;
+use std::process::Command;
+
+use log::info;
+
+use super::examples::get_examples_path;
+use super::years::get_puzzle_count;
+
+const DAY_TEMPLATE: &str = include_str!("../template/rust/day.rs");
+const EXAMPLES_TEMPLATE: &str = include_str!("../template/rust/examples.txt");
+const YEAR_TEMPLATES: [(&str, &str); 4] = [
+    (
+        "Cargo.toml",
+        include_str!("../template/rust/year/Cargo.toml"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../template/rust/year/src/lib.rs"),
+    ),
+    (
+        "src/main.rs",
+        include_str!("../template/rust/year/src/main.rs"),
+    ),
+    (
+        "src/days/mod.rs",
+        include_str!("../template/rust/year/src/days/mod.rs"),
+    ),
+];
+
+const REGISTER_DAYS: &str = "aoc_helpers::register_days!(";
+
+/// Create the solution and examples files for a day and register it, creating the year crate if needed
+///
+/// Returns the paths of every file that was created or changed.
+///
+/// # Errors
+///
+/// Will return `Err` if the day is invalid, the solution already exists or a file cannot be updated
+pub fn create_day(year: u16, day: u8) -> Result<Vec<String>, String> {
+    if day == 0 || day > get_puzzle_count(year) {
+        return Err(format!("Invalid day provided: {day}"));
+    }
+
+    let mut changed: Vec<String> = Vec::new();
+    if !has_year_crate(Path::new(&year.to_string()))? {
+        changed.extend(create_year(year)?);
+    }
+
+    let module = format!("day{day:02}");
+    let file_path = format!("{year}/src/days/{module}.rs");
+    if Path::new(&file_path).exists() || Path::new(&format!("{year}/src/days/{module}")).exists() {
+        return Err(format!(
+            "Solution already exists for year {year:04} day {day:02}"
+        ));
+    }
+    write_file(&file_path, &render(DAY_TEMPLATE, year, day))?;
+    changed.push(file_path);
+
+    let examples_path = get_examples_path(year, day);
+    if !Path::new(&examples_path).exists() {
+        write_file(&examples_path, EXAMPLES_TEMPLATE)?;
+        changed.push(examples_path);
+    }
+
+    let mod_path = format!("{year}/src/days/mod.rs");
+    update_file(&mod_path, |contents| add_day_module(contents, &module))?;
+    changed.push(mod_path);
+
+    Ok(changed)
+}
+
+/// Check whether a year directory holds a Rust crate that days can be added to
+///
+/// # Errors
+///
+/// Will return `Err` if the directory exists but is not a Rust year crate
+fn has_year_crate(directory: &Path) -> Result<bool, String> {
+    if !directory.exists() {
+        return Ok(false);
+    }
+    for file_name in ["Cargo.toml", "src/days/mod.rs"] {
+        let file_path = directory.join(file_name);
+        if !file_path.is_file() {
+            return Err(format!(
+                "{} is not a Rust year crate, could not find {}",
+                directory.display(),
+                file_path.display()
+            ));
+        }
+    }
+    Ok(true)
+}
+
+/// Create a new year crate and register it with the runner
+///
+/// # Errors
+///
+/// Will return `Err` if a file cannot be created or updated
+fn create_year(year: u16) -> Result<Vec<String>, String> {
+    info!("Creating crate for year {year:04}");
+    let mut changed: Vec<String> = Vec::new();
+    for (file_name, template) in YEAR_TEMPLATES {
+        let file_path = format!("{year}/{file_name}");
+        write_file(&file_path, &render(template, year, 0))?;
+        changed.push(file_path);
+    }
+
+    // The runner registers every year crate it depends on, see build.rs
+    let status = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(["add", "--path", &year.to_string()])
+        .status()
+        .map_err(|error| format!("Could not run cargo add: {error}"))?;
+    if !status.success() {
+        return Err(format!(
+            "Could not add the year {year:04} crate as a dependency"
+        ));
+    }
+    changed.push("Cargo.toml".to_string());
+
+    Ok(changed)
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &format!("{day:02}"))
+}
+
+fn write_file(file_path: &str, contents: &str) -> Result<(), String> {
+    if let Some(parent) = Path::new(file_path).parent() {
+        create_dir_all(parent).map_err(|error| format!("Could not create {file_path}: {error}"))?;
+    }
+    write(file_path, contents).map_err(|error| format!("Could not write {file_path}: {error}"))
+}
+
+fn update_file<F: FnOnce(&str) -> Result<String, String>>(
+    file_path: &str,
+    update: F,
+) -> Result<(), String> {
+    let contents = read_to_string(file_path)
+        .map_err(|error| format!("Could not read {file_path}: {error}"))?;
+    write_file(file_path, &update(&contents)?)
+}
+
+/// Add a day module to the `register_days!` invocation, keeping the days in order
+fn add_day_module(contents: &str, module: &str) -> Result<String, String> {
+    let start = contents
+        .find(REGISTER_DAYS)
+        .ok_or_else(|| "Could not find `register_days!` in days/mod.rs".to_string())?;
+    let list_start = start + REGISTER_DAYS.len();
+    let list_end = list_start
+        + contents[list_start..]
+            .find(')')
+            .ok_or_else(|| "Could not find the end of `register_days!`".to_string())?;
+
+    let mut modules: Vec<&str> = contents[list_start..list_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .collect();
+    if modules.contains(&module) {
+        return Err(format!("`{module}` is already registered"));
+    }
+    modules.push(module);
+    modules.sort_unstable();
+
+    let list: String = modules
+        .iter()
+        .map(|module| format!("    {module},\n"))
+        .collect();
+    Ok(format!(
+        "{}\n{list}{}",
+        &contents[..list_start],
+        &contents[list_end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let output = render(DAY_TEMPLATE, 2025, 7);
+
+        assert!(output.contains("pub const SOLUTION: &dyn SolveModule = &Day07;"));
+        assert!(!output.contains("__"));
+        assert!(render(YEAR_TEMPLATES[0].1, 2026, 0).contains("advent_of_code_2026"));
+    }
+
+    #[test]
+    fn test_has_year_crate() {
+        let directory = temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let rust_year = directory.join("2017");
+        let other_year = directory.join("2016");
+        create_dir_all(rust_year.join("src/days")).unwrap();
+        create_dir_all(other_year.join("day01")).unwrap();
+        write(rust_year.join("Cargo.toml"), "").unwrap();
+        write(rust_year.join("src/days/mod.rs"), "").unwrap();
+
+        assert_eq!(has_year_crate(&rust_year), Ok(true));
+        assert_eq!(has_year_crate(&directory.join("2026")), Ok(false));
+        assert!(
+            has_year_crate(&other_year).is_err_and(|error| error.contains("not a Rust year crate"))
+        );
+
+        remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_add_day_module() {
+        let data: [(&str, &str, &str); 2] = [
+            (
+                "aoc_helpers::register_days!();\n",
+                "day01",
+                "aoc_helpers::register_days!(\n    day01,\n);\n",
+            ),
+            (
+                "aoc_helpers::register_days!(\n    day01,\n    day03,\n);\n",
+                "day02",
+                "aoc_helpers::register_days!(\n    day01,\n    day02,\n    day03,\n);\n",
+            ),
+        ];
+
+        for (input, module, expected) in data {
+            assert_eq!(add_day_module(input, module), Ok(expected.to_string()));
+        }
+        assert!(add_day_module("aoc_helpers::register_days!(day01);", "day01").is_err());
+    }
+}
@@ -0,0 +1,502 @@
+use std::fmt;
+use std::fs::{OpenOptions, create_dir_all, read_to_string};
+use std::io::{Error, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use log::{info, trace, warn};
+
+use super::answers::{Answers, escape, read_answers, unescape, write_answers};
+use super::fetch::{Client, ClientConfig};
+use super::inputs::DayInput;
+use super::runner::{Outcome, run_part};
+
+/// How the server judged a submitted answer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Response {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The previous answer was submitted too recently so this one was not checked
+    TooRecent,
+    /// The part is already solved or not unlocked yet, treated as solved
+    WrongLevel,
+    /// The response could not be interpreted
+    Unknown,
+}
+
+impl fmt::Display for Response {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Correct => "CORRECT",
+            Self::Incorrect => "INCORRECT",
+            Self::TooHigh => "TOO_HIGH",
+            Self::TooLow => "TOO_LOW",
+            Self::TooRecent => "TOO_RECENT",
+            Self::WrongLevel => "WRONG_LEVEL",
+            Self::Unknown => "UNKNOWN",
+        })
+    }
+}
+
+impl FromStr for Response {
+    type Err = String;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "CORRECT" => Ok(Self::Correct),
+            "INCORRECT" => Ok(Self::Incorrect),
+            "TOO_HIGH" => Ok(Self::TooHigh),
+            "TOO_LOW" => Ok(Self::TooLow),
+            "TOO_RECENT" => Ok(Self::TooRecent),
+            "WRONG_LEVEL" => Ok(Self::WrongLevel),
+            "UNKNOWN" => Ok(Self::Unknown),
+            other => Err(format!("Invalid response: {other}")),
+        }
+    }
+}
+
+impl Response {
+    /// Whether the server checked the answer and found it to be wrong
+    #[inline]
+    #[must_use]
+    pub const fn is_wrong(self) -> bool {
+        matches!(self, Self::Incorrect | Self::TooHigh | Self::TooLow)
+    }
+}
+
+/// A single answer submitted to the server
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    /// Seconds since the unix epoch when the answer was submitted
+    pub time: u64,
+    pub part: u8,
+    pub response: Response,
+    /// How long the server asked to wait before submitting another answer
+    pub wait: Duration,
+    pub answer: String,
+}
+
+impl fmt::Display for Attempt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.time,
+            self.part,
+            self.response,
+            self.wait.as_secs(),
+            escape(&self.answer)
+        )
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    /// Parse an attempt stored as `<time> <part> <response> <wait seconds> <answer>`
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.splitn(5, ' ').collect();
+        let [time, part, response, wait, answer] = fields[..] else {
+            return Err(format!("Invalid attempt: {line}"));
+        };
+        let number = |value: &str| {
+            value
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid attempt: {line}"))
+        };
+
+        Ok(Self {
+            time: number(time)?,
+            part: u8::try_from(number(part)?).map_err(|error| error.to_string())?,
+            response: response.parse()?,
+            wait: Duration::from_secs(number(wait)?),
+            answer: unescape(answer),
+        })
+    }
+}
+
+pub fn get_submissions_path(year: u16, day: u8) -> String {
+    format!("inputs/{year}/day/{day}/submissions")
+}
+
+/// Read every answer previously submitted for a given day
+///
+/// # Errors
+///
+/// Will return `Err` if the submissions file does not exist or cannot be read
+pub fn read_attempts(year: u16, day: u8) -> Result<Vec<Attempt>, Error> {
+    let file_contents = read_to_string(get_submissions_path(year, day))?;
+    Ok(file_contents
+        .lines()
+        .filter_map(|line| match line.parse::<Attempt>() {
+            Ok(attempt) => Some(attempt),
+            Err(error) => {
+                warn!("{error}");
+                None
+            }
+        })
+        .collect())
+}
+
+/// Append an attempt to the submissions of a given day
+///
+/// # Errors
+///
+/// Will return `Err` if the submissions file cannot be written
+pub fn append_attempt(year: u16, day: u8, attempt: &Attempt) -> Result<(), Error> {
+    let file_path = get_submissions_path(year, day);
+    if let Some(parent) = Path::new(&file_path).parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)?;
+    writeln!(file, "{attempt}")
+}
+
+/// Check an answer against earlier attempts before sending it to the server
+///
+/// # Errors
+///
+/// Will return `Err` with the reason if the answer should not be submitted
+pub fn check_attempt(attempts: &[Attempt], part: u8, answer: &str, now: u64) -> Result<(), String> {
+    // The server limits the rate of answers across both parts of a day
+    if let Some(last) = attempts.iter().max_by_key(|attempt| attempt.time) {
+        let allowed = last.time + last.wait.as_secs();
+        if now < allowed {
+            return Err(format!(
+                "Must wait {}s before submitting another answer",
+                allowed - now
+            ));
+        }
+    }
+
+    let attempts: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|attempt| attempt.part == part)
+        .collect();
+
+    if let Some(correct) = attempts
+        .iter()
+        .find(|attempt| attempt.response == Response::Correct)
+    {
+        return Err(format!(
+            "Part {part} was already solved with answer {}",
+            correct.answer
+        ));
+    }
+    if attempts
+        .iter()
+        .any(|attempt| attempt.response == Response::WrongLevel)
+    {
+        return Err(format!(
+            "Part {part} was already solved, the server did not check the last answer"
+        ));
+    }
+    if let Some(wrong) = attempts
+        .iter()
+        .find(|attempt| attempt.response.is_wrong() && attempt.answer == answer)
+    {
+        return Err(format!(
+            "Answer {answer} was already rejected as {}",
+            wrong.response
+        ));
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        let bound = |response: Response| {
+            attempts
+                .iter()
+                .filter(move |attempt| attempt.response == response)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Response::TooHigh).min()
+            && value >= high
+        {
+            return Err(format!(
+                "Answer {answer} is too high, {high} was already too high"
+            ));
+        }
+        if let Some(low) = bound(Response::TooLow).max()
+            && value <= low
+        {
+            return Err(format!(
+                "Answer {answer} is too low, {low} was already too low"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Interpret the page the server returns after submitting an answer
+///
+/// Returns the response and how long to wait before the next submission
+pub fn parse_response(body: &str) -> (Response, Duration) {
+    let text = get_message(body);
+
+    let response = if text.contains("That's the right answer") {
+        Response::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Response::TooHigh
+        } else if text.contains("your answer is too low") {
+            Response::TooLow
+        } else {
+            Response::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Response::TooRecent
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unknown
+    };
+
+    (response, parse_wait(&text).unwrap_or_default())
+}
+
+/// Extract the plain text of the main article of a page
+pub fn get_message(body: &str) -> String {
+    let article = body
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+
+    let mut text = String::with_capacity(article.len());
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Parse waits such as `You have 1m 30s left to wait` or `please wait 5 minutes`
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((_, rest)) = text.split_once("You have ")
+        && let Some((wait, _)) = rest.split_once(" left to wait")
+    {
+        let mut seconds = 0;
+        for token in wait.split_whitespace() {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value = value.parse::<u64>().ok()?;
+            seconds += match unit {
+                "h" => value * 60 * 60,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let value = match words.next()? {
+        "one" => 1,
+        value => value.parse::<u64>().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(value * 60))
+}
+
+/// Solve one part of a day, submit the answer and record the attempt
+///
+/// # Errors
+///
+/// Will return `Err` if the day cannot be solved, the answer is rejected before sending
+/// or the request fails
+pub fn submit_answer(
+    year: u16,
+    day: u8,
+    part: u8,
+    timeout: Option<Duration>,
+) -> Result<Response, String> {
+    let answer = match run_part(&DayInput::new(year, day), part, timeout) {
+        Ok(answer) => answer,
+        Err(Outcome::MissingSolution) => {
+            return Err(format!(
+                "Could not find solution for year {year:04} day {day:02}"
+            ));
+        }
+        Err(Outcome::MissingInput(file_path)) => {
+            return Err(format!("Could not read file at {file_path}"));
+        }
+        Err(Outcome::Panicked(message)) => return Err(format!("Solution panicked: {message}")),
+        Err(Outcome::TimedOut(timeout)) => {
+            return Err(format!("Solution timed out after {timeout:?}"));
+        }
+        Err(Outcome::InvalidInput(error)) => return Err(format!("Invalid input at {error}")),
+        Err(other) => return Err(format!("{other:?}")),
+    };
+    println!("{year:04} day {day:02} part {part}: submitting {answer}");
+
+    let attempts = read_attempts(year, day).unwrap_or_else(|_| {
+        trace!(
+            "No submissions recorded at `{}`",
+            get_submissions_path(year, day)
+        );
+        Vec::new()
+    });
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    check_attempt(&attempts, part, &answer, now)?;
+
+    let client = Client::new(ClientConfig::from_env()?);
+    let body = client.post_answer(year, day, part, &answer)?;
+    let (response, wait) = parse_response(&body);
+
+    let attempt = Attempt {
+        time: now,
+        part,
+        response,
+        wait,
+        answer,
+    };
+    if let Err(error) = append_attempt(year, day, &attempt) {
+        warn!("Could not record attempt: {error}");
+    }
+
+    println!("{year:04} day {day:02} part {part}: {response}");
+    if !wait.is_zero() {
+        println!("Wait {wait:?} before submitting again");
+    }
+    if response == Response::Unknown {
+        println!("{}", get_message(&body));
+    }
+    if response == Response::WrongLevel {
+        println!("Part {part} is already solved, later submissions of it will be refused");
+    }
+    if response == Response::Correct {
+        record_correct_answer(year, day, part, attempt.answer);
+    }
+    Ok(response)
+}
+
+/// Store a correct answer as the expected answer used by `--verify`
+fn record_correct_answer(year: u16, day: u8, part: u8, answer: String) {
+    let mut answers = read_answers(year, day).unwrap_or_else(|_| Answers::default());
+    if part == 1 {
+        answers.part1 = Some(answer);
+    } else {
+        answers.part2 = Some(answer);
+    }
+    match write_answers(year, day, &answers) {
+        Ok(()) => info!("Recorded part {part} answer for year {year:04} day {day:02}"),
+        Err(error) => warn!("Could not record answer: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(time: u64, response: Response, wait: u64, answer: &str) -> Attempt {
+        Attempt {
+            time,
+            part: 1,
+            response,
+            wait: Duration::from_secs(wait),
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let data: [(&str, (Response, Duration)); 6] = [
+            (
+                "<main><article><p>That's the right answer!  You are one gold star closer.</p></article></main>",
+                (Response::Correct, Duration::ZERO),
+            ),
+            (
+                "<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>",
+                (Response::TooHigh, Duration::from_secs(60)),
+            ),
+            (
+                "<article><p>That's not the right answer; your answer is too low.  please wait 5 minutes before trying again.</p></article>",
+                (Response::TooLow, Duration::from_secs(300)),
+            ),
+            (
+                "<article><p>That's not the right answer.  If you're stuck, please wait 10 minutes before trying again.</p></article>",
+                (Response::Incorrect, Duration::from_secs(600)),
+            ),
+            (
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 32s left to wait.</p></article>",
+                (Response::TooRecent, Duration::from_secs(92)),
+            ),
+            (
+                "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>",
+                (Response::WrongLevel, Duration::ZERO),
+            ),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(parse_response(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_attempt_round_trip() {
+        let attempt = Attempt {
+            part: 2,
+            ..attempt(1_700_000_000, Response::TooLow, 60, "two words\nand a line")
+        };
+
+        assert_eq!(attempt.to_string().parse::<Attempt>(), Ok(attempt));
+        assert!("1700000000 1 MAYBE 0 42".parse::<Attempt>().is_err());
+    }
+
+    #[test]
+    fn test_check_attempt() {
+        let attempts = [
+            attempt(100, Response::TooHigh, 60, "500"),
+            attempt(200, Response::TooLow, 60, "100"),
+            attempt(300, Response::Incorrect, 300, "250"),
+        ];
+
+        let data: [(&str, u64, bool); 7] = [
+            ("300", 1000, true),
+            ("250", 1000, false),
+            ("500", 1000, false),
+            ("600", 1000, false),
+            ("100", 1000, false),
+            ("50", 1000, false),
+            ("300", 500, false),
+        ];
+
+        for (answer, now, expected) in data {
+            assert_eq!(check_attempt(&attempts, 1, answer, now).is_ok(), expected);
+        }
+        assert!(check_attempt(&attempts, 2, "500", 1000).is_ok());
+        // The wait applies to both parts
+        assert!(check_attempt(&attempts, 2, "500", 500).is_err());
+    }
+
+    #[test]
+    fn test_check_attempt_solved() {
+        let attempts = [attempt(100, Response::Correct, 0, "ABC")];
+
+        assert!(check_attempt(&attempts, 1, "ABC", 1000).is_err());
+        assert!(check_attempt(&attempts, 1, "XYZ", 1000).is_err());
+
+        let attempts = [attempt(100, Response::WrongLevel, 0, "ABC")];
+        assert!(check_attempt(&attempts, 1, "XYZ", 1000).is_err());
+        assert!(check_attempt(&attempts, 2, "XYZ", 1000).is_ok());
+    }
+}
@@ -0,0 +1,425 @@
+use std::env;
+use std::fs::{metadata, read_dir};
+use std::path::Path;
+use std::process::Command;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+use super::answers::get_answers_path;
+use super::args::Mode;
+use super::bench::compare_runtimes;
+use super::examples::{ExampleResult, check_examples, get_examples_path, read_examples};
+use super::inputs::{DayInput, InputData, InputSource, get_day_inputs};
+use super::memory;
+use super::output::{DayRecord, OutputFormat};
+use super::results::handle_report;
+use super::runner::run_day;
+use super::years::get_solve_module;
+
+/// Time between checks of the watched files
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Last modification time and size of a file, `None` if it does not exist
+type FileState = Option<(SystemTime, u64)>;
+
+/// The result of running a day and its examples once
+#[derive(Clone, Debug, Default)]
+struct Iteration {
+    record: DayRecord,
+    /// `None` if the day has no examples file
+    examples: Option<Result<Vec<ExampleResult>, String>>,
+}
+
+/// Run a day every time its input, examples or answers change
+///
+/// The runner cannot reload a solution after it was compiled, so a change to the source of
+/// the day rebuilds the runner with cargo and restarts the watch with the new build. A build
+/// that fails keeps the current runner watching until the source changes again.
+///
+/// # Errors
+///
+/// Will return `Err` if the input of the day cannot be read from the given source or the
+/// rebuilt runner cannot be started
+pub fn watch_day(
+    year: u16,
+    day: u8,
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let input = get_day_inputs(&[(year, day)], source)?
+        .pop()
+        .ok_or_else(|| format!("No input found for year {year:04} day {day:02}"))?;
+    // Only the default input has recorded answers to verify against
+    let mode = if input.label.is_none() {
+        Mode::Verify
+    } else {
+        Mode::Run
+    };
+
+    let mut data_paths: Vec<String> = vec![get_examples_path(year, day)];
+    if let InputData::File(file_path) = &input.data {
+        data_paths.push(file_path.clone());
+    }
+    if mode == Mode::Verify {
+        data_paths.push(get_answers_path(year, day));
+    }
+    let source_paths = get_source_paths(year, day);
+    // Resolve the executable before a rebuild replaces it
+    let executable = env::current_exe()
+        .map_err(|error| format!("Could not find the path of the runner: {error}"))?;
+
+    println!(
+        "Watching {} for changes, press Ctrl-C to stop",
+        data_paths.join(", ")
+    );
+    let mut data_state = get_file_states(&data_paths);
+    let mut source_state = get_file_states(&source_paths);
+    let mut previous: Option<Iteration> = None;
+    let mut reason = "started".to_string();
+    for run in 1.. {
+        let current = run_iteration(&input, mode, timeout);
+        println!("\n{year:04} day {day:02}: run {run} ({reason})");
+        for line in format_changes(previous.as_ref(), &current) {
+            println!("{line}");
+        }
+        previous = Some(current);
+
+        loop {
+            sleep(POLL_INTERVAL);
+            let changed = get_changed_paths(&source_paths, &source_state);
+            if !changed.is_empty() {
+                println!("\n{} changed, rebuilding", changed.join(", "));
+                match rebuild() {
+                    Ok(()) => return restart(&executable),
+                    Err(error) => {
+                        println!("{error}, waiting for the solution to change");
+                        source_state = get_file_states(&source_paths);
+                        continue;
+                    }
+                }
+            }
+
+            let new_state = get_file_states(&data_paths);
+            let changed = get_changed_paths(&data_paths, &data_state);
+            if !changed.is_empty() {
+                data_state = new_state;
+                reason = format!("{} changed", changed.join(", "));
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Build the runner with the same profile and features as the running build
+fn rebuild() -> Result<(), String> {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.arg("build");
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if memory::ENABLED {
+        command.args(["--features", "memory"]);
+    }
+
+    let status = command
+        .status()
+        .map_err(|error| format!("Could not run cargo: {error}"))?;
+    if !status.success() {
+        return Err(format!("Build failed ({status})"));
+    }
+    Ok(())
+}
+
+/// Replace the running process with a new run of the executable using the same arguments
+#[cfg(unix)]
+fn restart(executable: &Path) -> Result<(), String> {
+    use std::os::unix::process::CommandExt;
+
+    let error = Command::new(executable).args(env::args_os().skip(1)).exec();
+    Err(format!(
+        "Could not restart {}: {error}",
+        executable.display()
+    ))
+}
+
+/// Run the executable with the same arguments and exit with its status once it stops
+#[cfg(not(unix))]
+fn restart(executable: &Path) -> Result<(), String> {
+    let status = Command::new(executable)
+        .args(env::args_os().skip(1))
+        .status()
+        .map_err(|error| format!("Could not restart {}: {error}", executable.display()))?;
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+fn run_iteration(input: &DayInput, mode: Mode, timeout: Option<Duration>) -> Iteration {
+    let report = run_day(input, timeout);
+    let record = handle_report(report, mode, OutputFormat::Text);
+    let examples = get_solve_module(input.year, input.day)
+        .ok()
+        .and_then(|solution| {
+            read_examples(input.year, input.day).map(|examples| {
+                examples.map(|examples| check_examples(solution, &examples, timeout))
+            })
+        });
+    Iteration { record, examples }
+}
+
+/// The source file of a day, or every file of its module directory
+fn get_source_paths(year: u16, day: u8) -> Vec<String> {
+    let module_path = format!("{year}/src/days/day{day:02}");
+    let mut paths: Vec<String> = match read_dir(&module_path) {
+        Ok(entries) => entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .map(|file_name| format!("{module_path}/{file_name}"))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    paths.push(format!("{module_path}.rs"));
+    paths.sort_unstable();
+    paths
+}
+
+fn get_file_states(paths: &[String]) -> Vec<FileState> {
+    paths
+        .iter()
+        .map(|path| {
+            let metadata = metadata(path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        })
+        .collect()
+}
+
+fn get_changed_paths(paths: &[String], previous: &[FileState]) -> Vec<String> {
+    paths
+        .iter()
+        .zip(get_file_states(paths))
+        .zip(previous)
+        .filter(|((_, current), previous)| current != *previous)
+        .map(|((path, _), _)| path.clone())
+        .collect()
+}
+
+/// Format the result of a run next to the previous run, followed by any failures
+fn format_changes(previous: Option<&Iteration>, current: &Iteration) -> Vec<String> {
+    let previous_record = previous.map(|iteration| &iteration.record);
+    let mut rows: Vec<[String; 4]> = vec![[
+        String::new(),
+        "Previous".to_string(),
+        "Current".to_string(),
+        "Change".to_string(),
+    ]];
+
+    let field = |record: Option<&DayRecord>, get: &dyn Fn(&DayRecord) -> Option<String>| {
+        record.and_then(get).unwrap_or_else(|| "-".to_string())
+    };
+    let mut add_row = |name: &str, get: &dyn Fn(&DayRecord) -> Option<String>| {
+        let before = field(previous_record, get);
+        let after = field(Some(&current.record), get);
+        let change = if previous.is_none() || before == after {
+            String::new()
+        } else {
+            "changed".to_string()
+        };
+        rows.push([name.to_string(), before, after, change]);
+    };
+    add_row("Status", &|record| Some(record.status.to_string()));
+    add_row("Part 1", &|record| record.part1.clone());
+    add_row("Part 2", &|record| record.part2.clone());
+    add_row("Verdict 1", &|record| {
+        record.part1_verdict.map(|verdict| verdict.to_string())
+    });
+    add_row("Verdict 2", &|record| {
+        record.part2_verdict.map(|verdict| verdict.to_string())
+    });
+
+    let times = [
+        (
+            "Parse",
+            previous_record.and_then(|record| record.parse_time),
+            current.record.parse_time,
+        ),
+        (
+            "Part 1 time",
+            previous_record.and_then(|record| record.part1_time),
+            current.record.part1_time,
+        ),
+        (
+            "Part 2 time",
+            previous_record.and_then(|record| record.part2_time),
+            current.record.part2_time,
+        ),
+    ];
+    for (name, before, after) in times {
+        let format = |time: Option<Duration>| {
+            time.map_or_else(|| "-".to_string(), |time| format!("{time:?}"))
+        };
+        rows.push([
+            name.to_string(),
+            format(before),
+            format(after),
+            compare_times(before, after),
+        ]);
+    }
+
+    let previous_examples =
+        previous.and_then(|iteration| format_examples(iteration.examples.as_ref()));
+    let current_examples = format_examples(current.examples.as_ref());
+    if previous_examples.is_some() || current_examples.is_some() {
+        let change = if previous.is_none() || previous_examples == current_examples {
+            String::new()
+        } else {
+            "changed".to_string()
+        };
+        rows.push([
+            "Examples".to_string(),
+            previous_examples.unwrap_or_else(|| "-".to_string()),
+            current_examples.unwrap_or_else(|| "-".to_string()),
+            change,
+        ]);
+    }
+
+    let widths: Vec<usize> = (0..4)
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2]
+            )
+            .trim_end()
+            .to_string()
+        })
+        .collect();
+
+    if let Some(message) = &current.record.message {
+        lines.push(format!("{}: {message}", current.record.status));
+    }
+    match &current.examples {
+        Some(Ok(examples)) => lines.extend(
+            examples
+                .iter()
+                .filter(|example| example.status.is_failure())
+                .map(|example| {
+                    format!(
+                        "Example {} part {}: {} {}",
+                        example.name, example.part, example.status, example.detail
+                    )
+                }),
+        ),
+        Some(Err(error)) => lines.push(format!("Examples: {error}")),
+        None => {}
+    }
+    lines
+}
+
+/// Number of example parts that passed, or why the examples could not be run
+fn format_examples(examples: Option<&Result<Vec<ExampleResult>, String>>) -> Option<String> {
+    match examples? {
+        Ok(examples) => {
+            let passed = examples
+                .iter()
+                .filter(|example| !example.status.is_failure())
+                .count();
+            Some(format!("{passed}/{} passed", examples.len()))
+        }
+        Err(_) => Some("invalid".to_string()),
+    }
+}
+
+/// Describe the change in runtime relative to the previous run
+fn compare_times(previous: Option<Duration>, current: Option<Duration>) -> String {
+    previous
+        .zip(current)
+        .and_then(|(previous, current)| compare_runtimes(previous, current))
+        .map(|change| change.to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Verdict;
+    use crate::output::Status;
+
+    fn iteration(part1: &str, part1_time: u64, passed: bool) -> Iteration {
+        Iteration {
+            record: DayRecord {
+                part1: Some(part1.to_string()),
+                part2: Some("42".to_string()),
+                part1_verdict: Some(Verdict::Pass),
+                part2_verdict: Some(Verdict::Unknown),
+                parse_time: Some(Duration::from_millis(1)),
+                part1_time: Some(Duration::from_millis(part1_time)),
+                part2_time: Some(Duration::from_millis(5)),
+                ..DayRecord::new(2019, 12, Status::Ok)
+            },
+            examples: Some(Ok(vec![ExampleResult {
+                name: "example".to_string(),
+                part: 1,
+                status: if passed { Status::Ok } else { Status::Fail },
+                detail: if passed {
+                    "179".to_string()
+                } else {
+                    "expected `179`, got `180`".to_string()
+                },
+            }])),
+        }
+    }
+
+    #[test]
+    fn test_format_changes() {
+        let previous = iteration("7013", 20, true);
+        let current = iteration("7077", 10, false);
+
+        let lines = format_changes(None, &previous);
+        assert_eq!(lines[0], "             Previous  Current     Change");
+        assert_eq!(lines[2], "Part 1       -         7013");
+
+        let lines = format_changes(Some(&previous), &current);
+        let expected = [
+            "             Previous    Current     Change",
+            "Status       OK          OK",
+            "Part 1       7013        7077        changed",
+            "Part 2       42          42",
+            "Verdict 1    PASS        PASS",
+            "Verdict 2    UNKNOWN     UNKNOWN",
+            "Parse        1ms         1ms         no change",
+            "Part 1 time  20ms        10ms        2.00x faster",
+            "Part 2 time  5ms         5ms         no change",
+            "Examples     1/1 passed  0/1 passed  changed",
+            "Example example part 1: FAIL expected `179`, got `180`",
+        ];
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn test_compare_times() {
+        let data: [(Option<u64>, Option<u64>, &str); 4] = [
+            (None, Some(10), ""),
+            (Some(100), Some(102), "no change"),
+            (Some(100), Some(50), "2.00x faster"),
+            (Some(100), Some(150), "1.50x slower"),
+        ];
+
+        for (previous, current, expected) in data {
+            assert_eq!(
+                compare_times(
+                    previous.map(Duration::from_millis),
+                    current.map(Duration::from_millis)
+                ),
+                expected
+            );
+        }
+    }
+}
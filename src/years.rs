@@ -1,23 +1,106 @@
-use aoc_helpers::solve::SolveFunction;
+use aoc_helpers::solve::{DaySolution, SolveModule, find_solve_module};
+use log::warn;
 
-pub const YEARS: [u16; 4] = [2017, 2018, 2019, 2025];
+// The `YEARS` table, generated by build.rs from the year crates in the dependencies
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
 
-pub const fn get_max_days(year: u16) -> Option<u8> {
-    match year {
-        2017 => Some(advent_of_code_2017::days::MAX_DAY),
-        2018 => Some(advent_of_code_2018::days::MAX_DAY),
-        2019 => Some(advent_of_code_2019::days::MAX_DAY),
-        2025 => Some(advent_of_code_2025::days::MAX_DAY),
-        _ => None,
+/// Returns the solutions registered for a year
+pub fn get_days(year: u16) -> Option<&'static [DaySolution]> {
+    YEARS
+        .iter()
+        .find(|&&(registered, _)| registered == year)
+        .map(|&(_, days)| days)
+}
+
+/// Returns the number of puzzles released in a year
+pub const fn get_puzzle_count(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Returns the days of a year without a registered solution
+pub fn get_missing_days(year: u16) -> Vec<u8> {
+    let days = get_days(year).unwrap_or_default();
+    (1..=get_puzzle_count(year))
+        .filter(|&day| days.iter().all(|registered| registered.day != day))
+        .collect()
+}
+
+pub fn get_solve_module(year: u16, day: u8) -> Result<&'static dyn SolveModule, String> {
+    match get_days(year) {
+        Some(days) => find_solve_module(days, day),
+        None => Err(format!("Invalid year provided: {year}")),
+    }
+}
+
+/// Returns every (year, day) pair matching the given selection
+pub fn get_selected_days(year: Option<u16>, day: Option<u8>) -> Vec<(u16, u8)> {
+    let years: Vec<u16> = year.map_or_else(
+        || YEARS.iter().map(|&(year, _)| year).collect(),
+        |year| vec![year],
+    );
+
+    let mut result: Vec<(u16, u8)> = Vec::new();
+    for year in years {
+        if let Some(day) = day {
+            result.push((year, day));
+        } else if let Some(days) = get_days(year) {
+            result.extend(days.iter().map(|registered| (year, registered.day)));
+        } else {
+            warn!("Could not find solutions for year {year:04}");
+        }
     }
+    result
 }
 
-pub fn get_solve_module(year: u16, day: u8) -> Result<SolveFunction, String> {
-    match year {
-        2017 => advent_of_code_2017::days::get_solve_module(day),
-        2018 => advent_of_code_2018::days::get_solve_module(day),
-        2019 => advent_of_code_2019::days::get_solve_module(day),
-        2025 => advent_of_code_2025::days::get_solve_module(day),
-        other => Err(format!("Invalid year provided: {other}")),
+/// Prints the solved and missing days of each selected year
+pub fn list_days(year: Option<u16>) {
+    let years: Vec<u16> = year.map_or_else(
+        || YEARS.iter().map(|&(year, _)| year).collect(),
+        |year| vec![year],
+    );
+
+    for year in years {
+        let solved: Vec<u8> = get_days(year)
+            .unwrap_or_default()
+            .iter()
+            .map(|registered| registered.day)
+            .collect();
+        let missing = get_missing_days(year);
+        println!(
+            "{year:04}: {}/{} days solved",
+            solved.len(),
+            get_puzzle_count(year)
+        );
+        println!("    solved:  {}", format_days(&solved));
+        println!("    missing: {}", format_days(&missing));
+    }
+}
+
+fn format_days(days: &[u8]) -> String {
+    if days.is_empty() {
+        return "none".to_string();
+    }
+    days.iter()
+        .map(u8::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_missing_days() {
+        assert_eq!(get_missing_days(2017), Vec::<u8>::new());
+        assert_eq!(get_missing_days(2016), (1..=25).collect::<Vec<u8>>());
+        assert_eq!(get_missing_days(2099), (1..=12).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_get_selected_days() {
+        assert_eq!(get_selected_days(Some(2019), Some(5)), vec![(2019, 5)]);
+        assert_eq!(get_selected_days(Some(2025), None).len(), 12);
+        assert!(get_selected_days(Some(2016), None).is_empty());
     }
 }
@@ -0,0 +1,53 @@
+use aoc_helpers::io::ParseError;
+use aoc_helpers::solve::{Solution, SolveModule};
+
+pub const SOLUTION: &dyn SolveModule = &Day__DAY__;
+
+struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    type Input = Vec<String>;
+
+    fn try_parse(&self, file_contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(file_contents))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        solve_part_2(input).to_string()
+    }
+}
+
+fn parse_input(file_contents: &str) -> Vec<String> {
+    file_contents.lines().map(str::to_string).collect()
+}
+
+fn solve_part_1(_input: &[String]) -> i64 {
+    0
+}
+
+fn solve_part_2(_input: &[String]) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_helpers::examples::check_examples;
+
+    use super::*;
+
+    const EXAMPLES: &str = include_str!("../../examples/day__DAY__.txt");
+
+    #[test]
+    fn test_part_1() {
+        check_examples(SOLUTION, EXAMPLES, 1);
+    }
+
+    #[test]
+    fn test_part_2() {
+        check_examples(SOLUTION, EXAMPLES, 2);
+    }
+}
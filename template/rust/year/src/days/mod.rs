@@ -0,0 +1 @@
+aoc_helpers::register_days!();
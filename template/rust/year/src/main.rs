@@ -0,0 +1,4 @@
+fn main() {
+    env_logger::init();
+    aoc_helpers::solve::run_year_main(__YEAR__, advent_of_code___YEAR__::days::DAYS);
+}